@@ -0,0 +1,237 @@
+use core::marker::PhantomData;
+use core::ops::Mul;
+
+use crate::{AdditiveUnit, DerivableUnit, IntegrableUnit, Seconds, Unit};
+
+// ##############
+// #    Gain    #
+// ##############
+    /// A gain turning the input unit `I` into the output unit `O`, so `gain * input = output`
+    ///
+    /// The gain keeps the awkward units of controller gains in the type system, e.g. a [Gain] of `Millimeters` to `MMPerSecond` has
+    /// the unit `1/s` and can only be applied to `Millimeters`
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::control::Gain;
+    ///
+    /// let gain = Gain::<Millimeters, MMPerSecond>::new(2.0);
+    ///
+    /// assert_eq!(gain * Millimeters(3.0), MMPerSecond(6.0));
+    /// ```
+    #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+    pub struct Gain<I, O> {
+        value : f32,
+        _units : PhantomData<fn(I) -> O>
+    }
+
+    impl<I, O> Gain<I, O> {
+        /// A gain of zero, disabling the term of the controller it is used for
+        pub const ZERO : Self = Self::new(0.0);
+
+        /// Creates a new gain with the given `value`, one unit of `I` equals `value` units of `O`
+        pub const fn new(value : f32) -> Self {
+            Self { value, _units: PhantomData }
+        }
+
+        /// The [f32] value of the gain
+        pub const fn value(self) -> f32 {
+            self.value
+        }
+    }
+
+    impl<I : Unit, O : Unit> Mul<I> for Gain<I, O> {
+        type Output = O;
+
+        #[inline]
+        fn mul(self, rhs : I) -> Self::Output {
+            O::from(self.value * rhs.into())
+        }
+    }
+//
+
+// #############
+// #    PID    #
+// #############
+    /// A PID controller turning an error `E` into an output `O`, using the time unit `T` (by default [Seconds])
+    ///
+    /// All gains are typed, so every term of the controller results in the output unit:
+    ///
+    /// - `Kp` is a [Gain] of `E` to `O`
+    /// - `Ki` is a [Gain] of `E` to the rate of the output (`O / T`), which is integrated over time using [IntegrableUnit]
+    /// - `Kd` is a [Gain] of the rate of the error (`E / T`, see [DerivableUnit]) to `O`
+    ///
+    /// The output is clamped to the limits given with [Pid::with_limits], the integral term is clamped to the same limits to prevent
+    /// windup. The derivative can be smoothed with a first order low-pass filter using [Pid::with_derivative_filter]. The controller
+    /// does not allocate.
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::control::{Gain, Pid};
+    ///
+    /// // Position loop of an axis, commanding a velocity
+    /// let mut pid = Pid::<Millimeters, MMPerSecond>::new(
+    ///     Gain::new(2.0),         // (mm/s) / mm
+    ///     Gain::new(0.5),         // (mm/s^2) / mm
+    ///     Gain::new(0.1)          // (mm/s) / (mm/s)
+    /// ).with_limits(MMPerSecond(-100.0), MMPerSecond(100.0));
+    ///
+    /// // The first update has no derivative yet
+    /// assert_eq!(pid.update(Millimeters(10.0), Seconds(0.5)), MMPerSecond(22.5));
+    /// assert_eq!(pid.update(Millimeters(8.0), Seconds(0.5)), MMPerSecond(20.1));
+    ///
+    /// // Large errors saturate the output
+    /// assert_eq!(pid.update(Millimeters(1000.0), Seconds(0.5)), MMPerSecond(100.0));
+    /// ```
+    ///
+    /// The controller can be used for any [UnitSet](crate::UnitSet):
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::control::{Gain, Pid};
+    ///
+    /// fn position_loop<U : UnitSet>(kp : f32) -> Pid<U::Distance, U::Velocity, U::Time> {
+    ///     Pid::new(Gain::new(kp), Gain::ZERO, Gain::ZERO)
+    /// }
+    ///
+    /// let mut pid = position_loop::<Rotary>(4.0);
+    /// assert_eq!(pid.update(Radians(0.5), Seconds(0.01)), RadPerSecond(2.0));
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct Pid<E, O, T = Seconds>
+    where
+        T : AdditiveUnit,
+        E : AdditiveUnit + DerivableUnit<T>,
+        <E as DerivableUnit<T>>::Result : AdditiveUnit,
+        O : AdditiveUnit + DerivableUnit<T>,
+        <O as DerivableUnit<T>>::Result : IntegrableUnit<T, Result = O>
+    {
+        kp : Gain<E, O>,
+        ki : Gain<E, <O as DerivableUnit<T>>::Result>,
+        kd : Gain<<E as DerivableUnit<T>>::Result, O>,
+
+        min : O,
+        max : O,
+        filter : T,
+
+        integral : O,
+        derivative : <E as DerivableUnit<T>>::Result,
+        last_error : Option<E>
+    }
+
+    impl<E, O, T> Pid<E, O, T>
+    where
+        T : AdditiveUnit,
+        E : AdditiveUnit + DerivableUnit<T>,
+        <E as DerivableUnit<T>>::Result : AdditiveUnit,
+        O : AdditiveUnit + DerivableUnit<T>,
+        <O as DerivableUnit<T>>::Result : IntegrableUnit<T, Result = O>
+    {
+        /// Creates a new controller with the gains `kp`, `ki` and `kd`, without output limits and without derivative filter
+        pub fn new(kp : Gain<E, O>, ki : Gain<E, <O as DerivableUnit<T>>::Result>, kd : Gain<<E as DerivableUnit<T>>::Result, O>) -> Self {
+            Self {
+                kp,
+                ki,
+                kd,
+
+                min: O::NEG_INFINITY,
+                max: O::INFINITY,
+                filter: T::ZERO,
+
+                integral: O::ZERO,
+                derivative: <E as DerivableUnit<T>>::Result::ZERO,
+                last_error: None
+            }
+        }
+
+        /// Limits the output and the integral term of the controller to the range `min ..= max`
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if `min` is greater than `max` (or either is NaN), as the output would always be `max` otherwise.
+        /// The bounds are not swapped, in release builds swapped limits are used as given
+        pub fn with_limits(mut self, min : O, max : O) -> Self {
+            debug_assert!(min <= max, "The minimum output limit {} is greater than the maximum {}", min, max);
+
+            self.min = min;
+            self.max = max;
+            self.integral = self.clamp(self.integral);
+            self
+        }
+
+        /// Filters the derivative with a first order low-pass of the given `time_constant`, a time constant of zero disables the filter
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// use syunit::control::{Gain, Pid};
+        ///
+        /// let mut pid = Pid::<Millimeters, MMPerSecond>::new(Gain::ZERO, Gain::ZERO, Gain::new(1.0))
+        ///     .with_derivative_filter(Seconds(1.0));
+        ///
+        /// pid.update(Millimeters(0.0), Seconds(1.0));
+        ///
+        /// // A step of the error only passes halfway through the filter
+        /// assert_eq!(pid.update(Millimeters(2.0), Seconds(1.0)), MMPerSecond(1.0));
+        /// assert_eq!(pid.update(Millimeters(2.0), Seconds(1.0)), MMPerSecond(0.5));
+        /// ```
+        pub fn with_derivative_filter(mut self, time_constant : T) -> Self {
+            self.filter = time_constant;
+            self
+        }
+
+        /// The proportional gain
+        pub fn kp(&self) -> Gain<E, O> {
+            self.kp
+        }
+
+        /// The integral gain
+        pub fn ki(&self) -> Gain<E, <O as DerivableUnit<T>>::Result> {
+            self.ki
+        }
+
+        /// The derivative gain
+        pub fn kd(&self) -> Gain<<E as DerivableUnit<T>>::Result, O> {
+            self.kd
+        }
+
+        /// The current value of the integral term
+        pub fn integral(&self) -> O {
+            self.integral
+        }
+
+        /// Resets the integral term and the derivative, keeping the gains, limits and filter
+        pub fn reset(&mut self) {
+            self.integral = O::ZERO;
+            self.derivative = <E as DerivableUnit<T>>::Result::ZERO;
+            self.last_error = None;
+        }
+
+        /// Clamps the `value` to the limits of the controller
+        fn clamp(&self, value : O) -> O {
+            value.max(self.min).min(self.max)
+        }
+
+        /// Updates the controller with the current `error` and the time `dt` passed since the last update, returning the new output
+        ///
+        /// The derivative is only updated if `dt` is positive and a previous error is known, so the first update after creating or
+        /// resetting the controller has no derivative term
+        pub fn update(&mut self, error : E, dt : T) -> O {
+            if dt > T::ZERO {
+                // Integral term, clamped to prevent windup
+                self.integral = self.clamp(self.integral + (self.ki * error) * dt);
+
+                // Derivative term, smoothed by the low-pass filter
+                if let Some(last_error) = self.last_error {
+                    let rate = (error - last_error) / dt;
+                    let alpha = dt / (self.filter + dt);
+
+                    self.derivative = self.derivative + (rate - self.derivative) * alpha;
+                }
+
+                self.last_error = Some(error);
+            }
+
+            self.clamp(self.kp * error + self.integral + self.kd * self.derivative)
+        }
+    }
+//
@@ -0,0 +1,185 @@
+use core::fmt::{Display, Formatter};
+use core::time::Duration;
+
+use crate::{Hertz, Seconds, Timestamp};
+
+// ################
+// #    Errors    #
+// ################
+    /// Error returned when a time unit cannot be converted into a [Duration]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum DurationError {
+        /// The value is negative, durations are always positive
+        Negative,
+        /// The value is `NaN`
+        NaN,
+        /// The value is too large (or infinite) to be represented by a [Duration]
+        Overflow
+    }
+
+    impl Display for DurationError {
+        fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::Negative => f.write_str("cannot convert a negative value into a duration"),
+                Self::NaN => f.write_str("cannot convert NaN into a duration"),
+                Self::Overflow => f.write_str("the value is too large to be converted into a duration")
+            }
+        }
+    }
+
+    /// Converts the given amount of `secs` into a [Duration], rounding to the nearest nanosecond
+    fn duration_from_secs(secs : f64) -> Result<Duration, DurationError> {
+        if secs.is_nan() {
+            Err(DurationError::NaN)
+        } else if secs < 0.0 {
+            Err(DurationError::Negative)
+        } else {
+            Duration::try_from_secs_f64(secs).map_err(|_| DurationError::Overflow)
+        }
+    }
+//
+
+// #################
+// #    Seconds    #
+// #################
+    impl Seconds {
+        /// Creates a new duration out of the given amount of nanoseconds
+        ///
+        /// The division is done with [f64], so the result is the [f32] value closest to the given amount of nanoseconds
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Seconds::from_nanos(1_500_000_000), Seconds(1.5));
+        /// assert_eq!(Seconds::from_nanos(250), Seconds(0.000_000_25));
+        /// ```
+        #[inline]
+        pub fn from_nanos(nanos : u64) -> Self {
+            Self((nanos as f64 / 1e9) as f32)
+        }
+
+        /// Converts the duration into a [Duration], saturating at [Duration::ZERO] for negative values and `NaN`, and at
+        /// [Duration::MAX] for values too large to be represented
+        ///
+        /// ```rust
+        /// use core::time::Duration;
+        ///
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Seconds(1.5).to_duration_saturating(), Duration::from_millis(1500));
+        /// assert_eq!(Seconds(-1.0).to_duration_saturating(), Duration::ZERO);
+        /// assert_eq!(Seconds::INFINITY.to_duration_saturating(), Duration::MAX);
+        /// ```
+        #[inline]
+        pub fn to_duration_saturating(self) -> Duration {
+            match duration_from_secs(self.0 as f64) {
+                Ok(duration) => duration,
+                Err(DurationError::Overflow) => Duration::MAX,
+                Err(_) => Duration::ZERO
+            }
+        }
+    }
+
+    impl TryFrom<Seconds> for Duration {
+        type Error = DurationError;
+
+        /// Converts the seconds into a [Duration], exact to the nanosecond of the [f32] value
+        ///
+        /// ```rust
+        /// use core::time::Duration;
+        ///
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Duration::try_from(Seconds(2.5)), Ok(Duration::from_millis(2500)));
+        /// assert_eq!(Duration::try_from(Seconds(3.0) / -2.0), Err(DurationError::Negative));
+        /// assert_eq!(Duration::try_from(Seconds::NAN), Err(DurationError::NaN));
+        /// assert_eq!(Duration::try_from(Seconds::INFINITY), Err(DurationError::Overflow));
+        /// ```
+        #[inline]
+        fn try_from(value : Seconds) -> Result<Self, Self::Error> {
+            duration_from_secs(value.0 as f64)
+        }
+    }
+
+    impl From<Duration> for Seconds {
+        /// Converts the duration into seconds, rounded once to the closest [f32] value
+        #[inline]
+        fn from(value : Duration) -> Self {
+            Self(value.as_secs_f64() as f32)
+        }
+    }
+//
+
+// ###############
+// #    Hertz    #
+// ###############
+    impl Hertz {
+        /// Creates the frequency of something happening once per `period`, a period of zero results in an infinite frequency
+        ///
+        /// ```rust
+        /// use core::time::Duration;
+        ///
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Hertz::from_period(Duration::from_millis(4)), Hertz(250.0));
+        /// assert_eq!(Hertz::from_period(Duration::ZERO), Hertz::INFINITY);
+        /// ```
+        #[inline]
+        pub fn from_period(period : Duration) -> Self {
+            Self((1.0 / period.as_secs_f64()) as f32)
+        }
+
+        /// The period of the frequency as [Duration], exact to the nanosecond
+        ///
+        /// ```rust
+        /// use core::time::Duration;
+        ///
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Hertz(250.0).period(), Ok(Duration::from_millis(4)));
+        /// assert_eq!(Hertz(3.0).period(), Ok(Duration::from_nanos(333_333_333)));
+        ///
+        /// // A frequency of zero has no period
+        /// assert_eq!(Hertz(0.0).period(), Err(DurationError::Overflow));
+        /// assert_eq!(Hertz(-1.0).period(), Err(DurationError::Negative));
+        /// ```
+        #[inline]
+        pub fn period(self) -> Result<Duration, DurationError> {
+            duration_from_secs(1.0 / self.0 as f64)
+        }
+    }
+//
+
+// ###################
+// #    Timestamp    #
+// ###################
+    impl Timestamp {
+        /// Creates a timestamp out of the `duration` passed since the epoch
+        #[inline]
+        pub fn from_duration(duration : Duration) -> Self {
+            Self(duration.as_secs_f64() as f32)
+        }
+
+        /// Creates a timestamp out of the `ticks` of a monotonic counter started at the epoch and counting with the given
+        /// frequency `tick_freq`
+        ///
+        /// The division is done with [f64], so large tick counts do not lose precision before being converted
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// // A 32kHz RTC counter
+        /// assert_eq!(Timestamp::from_ticks(49_152, Hertz(32_768.0)), Timestamp(1.5));
+        /// ```
+        #[inline]
+        pub fn from_ticks(ticks : u64, tick_freq : Hertz) -> Self {
+            Self((ticks as f64 / tick_freq.0 as f64) as f32)
+        }
+
+        /// The duration passed since the epoch, fails if the timestamp is before the epoch or not finite
+        #[inline]
+        pub fn to_duration(self) -> Result<Duration, DurationError> {
+            duration_from_secs(self.0 as f64)
+        }
+    }
+//
@@ -0,0 +1,501 @@
+use core::fmt::{Display, Formatter, Write};
+use core::ops::{Div, Mul, Neg};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+// ###################
+// #    Dimension    #
+// ###################
+    /// The physical dimension of a unit, expressed as the exponents of the base dimensions
+    ///
+    /// The exponent of the angle is tracked like any other, so e.g. [RadPerSecond](crate::RadPerSecond) and [Hertz](crate::Hertz) do not
+    /// match. Where the static units treat angles as dimensionless, e.g. [Radians](crate::Radians) times
+    /// [Millimeters](crate::metric::Millimeters) results in [Millimeters](crate::metric::Millimeters), the angle has to be dropped explicitly
+    /// with [DynQuantity::without_angle]
+    ///
+    /// ```rust
+    /// use syunit::Dimension;
+    ///
+    /// let velocity = Dimension::LENGTH / Dimension::TIME;
+    ///
+    /// assert_eq!(velocity, Dimension::new(1, 0, -1, 0));
+    /// assert_eq!(velocity * Dimension::TIME, Dimension::LENGTH);
+    /// assert_eq!(format!("{}", velocity), "m/s");
+    /// ```
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct Dimension {
+        /// Exponent of the length dimension (m)
+        pub length : i8,
+        /// Exponent of the mass dimension (kg)
+        pub mass : i8,
+        /// Exponent of the time dimension (s)
+        pub time : i8,
+        /// Exponent of the angle dimension (rad)
+        pub angle : i8
+    }
+
+    impl Dimension {
+        /// No dimension, the value is a pure number
+        pub const NONE : Self = Self::new(0, 0, 0, 0);
+        /// Length (m)
+        pub const LENGTH : Self = Self::new(1, 0, 0, 0);
+        /// Mass (kg)
+        pub const MASS : Self = Self::new(0, 1, 0, 0);
+        /// Time (s)
+        pub const TIME : Self = Self::new(0, 0, 1, 0);
+        /// Angle (rad)
+        pub const ANGLE : Self = Self::new(0, 0, 0, 1);
+
+        /// Creates a new dimension out of the exponents of the base dimensions
+        pub const fn new(length : i8, mass : i8, time : i8, angle : i8) -> Self {
+            Self { length, mass, time, angle }
+        }
+
+        /// Raises the dimension to the given integer power `pow`
+        ///
+        /// ```rust
+        /// use syunit::Dimension;
+        ///
+        /// assert_eq!(Dimension::LENGTH.powi(3), Dimension::new(3, 0, 0, 0));
+        /// ```
+        pub const fn powi(self, pow : i8) -> Self {
+            Self::new(self.length * pow, self.mass * pow, self.time * pow, self.angle * pow)
+        }
+
+        /// The dimension without the exponent of the angle
+        pub const fn without_angle(self) -> Self {
+            Self::new(self.length, self.mass, self.time, 0)
+        }
+
+        /// Returns the exponents paired with their SI symbols
+        fn exponents(self) -> [(&'static str, i8); 4] {
+            [ ("kg", self.mass), ("m", self.length), ("rad", self.angle), ("s", self.time) ]
+        }
+    }
+
+    impl Mul<Dimension> for Dimension {
+        type Output = Dimension;
+
+        #[inline]
+        fn mul(self, rhs : Dimension) -> Self::Output {
+            Self::new(self.length + rhs.length, self.mass + rhs.mass, self.time + rhs.time, self.angle + rhs.angle)
+        }
+    }
+
+    impl Div<Dimension> for Dimension {
+        type Output = Dimension;
+
+        #[inline]
+        fn div(self, rhs : Dimension) -> Self::Output {
+            self * rhs.powi(-1)
+        }
+    }
+
+    impl Display for Dimension {
+        /// Writes the dimension using SI symbols, e.g. `kg*m/s^2`, a dimensionless value is written as `1`
+        fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+            fn write_factor(f : &mut Formatter<'_>, first : &mut bool, sym : &str, exp : i8) -> core::fmt::Result {
+                if !*first {
+                    f.write_char('*')?;
+                }
+                *first = false;
+
+                if exp == 1 {
+                    f.write_str(sym)
+                } else {
+                    write!(f, "{}^{}", sym, exp)
+                }
+            }
+
+            let mut first = true;
+
+            for (sym, exp) in self.exponents() {
+                if exp > 0 {
+                    write_factor(f, &mut first, sym, exp)?;
+                }
+            }
+
+            if first {
+                f.write_char('1')?;
+            }
+
+            let mut first_denom = true;
+
+            for (sym, exp) in self.exponents() {
+                if exp < 0 {
+                    if first_denom {
+                        f.write_char('/')?;
+                    }
+                    write_factor(f, &mut first_denom, sym, -exp)?;
+                }
+            }
+
+            Ok(())
+        }
+    }
+//
+
+// ################
+// #    Errors    #
+// ################
+    /// Error returned when two quantities or a quantity and a unit do not fit together
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum DimensionError {
+        /// The dimensions do not match
+        Mismatch {
+            /// The dimension that was expected
+            expected : Dimension,
+            /// The dimension that was found instead
+            found : Dimension
+        },
+        /// A position was given where a distance was expected, or the other way around
+        PositionMismatch {
+            /// `true` if a position was expected, `false` if a distance was expected
+            position_expected : bool
+        }
+    }
+
+    impl Display for DimensionError {
+        fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::Mismatch { expected, found } => write!(f, "dimension mismatch, expected `{}`, found `{}`", expected, found),
+                Self::PositionMismatch { position_expected: true } => f.write_str("expected a position, found a distance"),
+                Self::PositionMismatch { position_expected: false } => f.write_str("expected a distance, found a position")
+            }
+        }
+    }
+
+    /// Error returned when parsing a [DynQuantity] fails
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ParseDynQuantityError {
+        /// The string does not start with a valid number
+        InvalidNumber,
+        /// The symbol following the number is not known, see [UNITS](crate::UNITS)
+        UnknownSymbol
+    }
+
+    impl Display for ParseDynQuantityError {
+        fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::InvalidNumber => f.write_str("the quantity does not start with a valid number"),
+                Self::UnknownSymbol => f.write_str("the symbol of the quantity is unknown")
+            }
+        }
+    }
+//
+
+// #####################
+// #    DynQuantity    #
+// #####################
+    /// A quantity whose unit is only known at runtime, useful for configuration, scripting and UI layers
+    ///
+    /// The quantity consists out of a `value`, its `dimension` and the `scale` of its unit, meaning `value * scale` equals the
+    /// value in SI units (m, kg, s, rad). The scale is stored as [f64] to keep conversions between prefixed units exact.
+    ///
+    /// All operations check the dimensions at runtime, conversions into the static units are done with [TryFrom], failing if the
+    /// dimensions do not match.
+    ///
+    /// Like the static units, a quantity is either a distance or an absolute [position](DynQuantity::position). Quantities created out of
+    /// [PositionUnits](crate::PositionUnit) are positions and can only be converted back into position units. The arithmetic follows the
+    /// static units, e.g. two positions cannot be added, and the difference of two positions is a distance.
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    ///
+    /// let dist : DynQuantity = "12.5mm".parse().unwrap();
+    /// let time : DynQuantity = "2.5 s".parse().unwrap();
+    ///
+    /// // Runtime checked arithmetic
+    /// let vel = dist / time;
+    /// assert_eq!(MMPerSecond::try_from(vel), Ok(MMPerSecond(5.0)));
+    /// assert!(dist.try_add(time).is_err());
+    ///
+    /// // Conversions with scaling
+    /// let dist_m = DynQuantity::from(Meters(0.5));
+    /// assert_eq!(Millimeters::try_from(dist.try_add(dist_m).unwrap()), Ok(Millimeters(512.5)));
+    ///
+    /// // Mismatching dimensions
+    /// assert!(Millimeters::try_from(vel).is_err());
+    ///
+    /// // Positions and distances
+    /// let pos = DynQuantity::from(PositionMM(10.0));
+    /// assert!(PositionMM::try_from(dist).is_err());
+    /// assert_eq!(PositionMM::try_from(pos.try_add(dist).unwrap()), Ok(PositionMM(22.5)));
+    /// assert_eq!(Millimeters::try_from(pos.try_sub(pos).unwrap()), Ok(Millimeters(0.0)));
+    /// assert!(pos.try_add(pos).is_err());
+    /// assert!(Timestamp::try_from(DynQuantity::from(Seconds(2.0))).is_err());
+    ///
+    /// // Angles are checked, unless dropped explicitly like for `Radians * Millimeters = Millimeters`
+    /// let arc = DynQuantity::from(Radians(2.0)) * DynQuantity::from(Millimeters(3.0));
+    /// assert!(Millimeters::try_from(arc).is_err());
+    /// assert_eq!(Millimeters::try_from(arc.without_angle()), Ok(Millimeters(6.0)));
+    /// assert!(Hertz::try_from(DynQuantity::from(RadPerSecond(6.28))).is_err());
+    /// assert!(RadPerSecond::try_from(DynQuantity::from(Hertz(1.0))).is_err());
+    /// assert!(Radians::try_from("5".parse::<DynQuantity>().unwrap()).is_err());
+    ///
+    /// // Parsing errors
+    /// assert_eq!("mm".parse::<DynQuantity>(), Err(ParseDynQuantityError::InvalidNumber));
+    /// assert_eq!("5 parsec".parse::<DynQuantity>(), Err(ParseDynQuantityError::UnknownSymbol));
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct DynQuantity {
+        /// The value in the unit described by `scale`
+        pub value : f32,
+        /// The dimension of the quantity
+        pub dimension : Dimension,
+        /// The scale of the unit, `value * scale` is the value in SI units
+        pub scale : f64,
+        /// Whether the quantity is an absolute position instead of a distance, see [PositionUnit](crate::PositionUnit)
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub position : bool
+    }
+
+    impl DynQuantity {
+        /// Creates a new quantity that is a distance, not a position
+        pub const fn new(value : f32, dimension : Dimension, scale : f64) -> Self {
+            Self { value, dimension, scale, position: false }
+        }
+
+        /// Creates a new quantity that is an absolute position
+        pub const fn new_position(value : f32, dimension : Dimension, scale : f64) -> Self {
+            Self { value, dimension, scale, position: true }
+        }
+
+        /// Returns the quantity marked as position (`true`) or distance (`false`)
+        ///
+        /// Parsed quantities are distances, as the symbol does not tell them apart
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// let pos = "12.5mm".parse::<DynQuantity>().unwrap().with_position(true);
+        ///
+        /// assert_eq!(PositionMM::try_from(pos), Ok(PositionMM(12.5)));
+        /// ```
+        #[inline]
+        pub const fn with_position(mut self, position : bool) -> Self {
+            self.position = position;
+            self
+        }
+
+        /// Returns the value in SI units (`value * scale`)
+        #[inline]
+        pub fn si_value(self) -> f32 {
+            (self.value as f64 * self.scale) as f32
+        }
+
+        /// Returns the value in the unit with the given `scale`
+        #[inline]
+        pub fn value_in(self, scale : f64) -> f32 {
+            if self.scale == scale {
+                self.value
+            } else {
+                (self.value as f64 * (self.scale / scale)) as f32
+            }
+        }
+
+        /// Converts the quantity into a unit with the given `scale`
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// let dist = DynQuantity::from(Millimeters(1500.0)).to_scale(1.0);
+        ///
+        /// assert_eq!(dist.value, 1.5);
+        /// ```
+        #[inline]
+        pub fn to_scale(self, scale : f64) -> Self {
+            Self { value: self.value_in(scale), scale, ..self }
+        }
+
+        /// Returns the symbol of the quantities unit, `None` if the unit is not in [UNITS](crate::UNITS)
+        pub fn symbol(&self) -> Option<&'static str> {
+            crate::UNITS.iter()
+                .find(|info| (info.dimension == self.dimension) && (info.scale_to_si == self.scale))
+                .map(|info| info.symbol)
+        }
+
+        /// Returns the quantity with the exponent of the angle dropped
+        ///
+        /// All checks compare the full [Dimension], use this where the static units treat angles as dimensionless, e.g. for torques
+        /// or arc lengths
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// let torque = DynQuantity::from(KgMeter2(2.0)) * DynQuantity::from(RadPerSecond2(3.0));
+        ///
+        /// assert!(NewtonMeters::try_from(torque).is_err());
+        /// assert_eq!(torque.without_angle().symbol(), Some("Nm"));
+        /// assert_eq!(NewtonMeters::try_from(torque.without_angle()), Ok(NewtonMeters(6.0)));
+        /// ```
+        #[inline]
+        pub const fn without_angle(mut self) -> Self {
+            self.dimension = self.dimension.without_angle();
+            self
+        }
+
+        /// Makes sure the dimension of this quantity equals the `expected` one
+        pub fn check_dimension(&self, expected : Dimension) -> Result<(), DimensionError> {
+            if self.dimension == expected {
+                Ok(())
+            } else {
+                Err(DimensionError::Mismatch { expected, found: self.dimension })
+            }
+        }
+
+        /// Makes sure this quantity is a position if `position` is `true`, or a distance if it is `false`
+        pub fn check_position(&self, position : bool) -> Result<(), DimensionError> {
+            if self.position == position {
+                Ok(())
+            } else {
+                Err(DimensionError::PositionMismatch { position_expected: position })
+            }
+        }
+
+        /// Adds two quantities, fails if the dimensions do not match or if both quantities are positions
+        ///
+        /// The result uses the unit (scale) of `self`, it is a position if one of the quantities is a position
+        pub fn try_add(self, rhs : Self) -> Result<Self, DimensionError> {
+            rhs.check_dimension(self.dimension)?;
+
+            if self.position {
+                rhs.check_position(false)?;
+            }
+
+            Ok(Self {
+                value: self.value + rhs.value_in(self.scale),
+                position: self.position || rhs.position,
+                ..self
+            })
+        }
+
+        /// Subtracts two quantities, fails if the dimensions do not match or if a position is subtracted from a distance
+        ///
+        /// The result uses the unit (scale) of `self`, the difference of two positions is a distance
+        pub fn try_sub(self, rhs : Self) -> Result<Self, DimensionError> {
+            rhs.check_dimension(self.dimension)?;
+
+            if !self.position {
+                rhs.check_position(false)?;
+            }
+
+            Ok(Self {
+                value: self.value - rhs.value_in(self.scale),
+                position: self.position && !rhs.position,
+                ..self
+            })
+        }
+    }
+
+    impl Mul<DynQuantity> for DynQuantity {
+        type Output = DynQuantity;
+
+        #[inline]
+        fn mul(self, rhs : DynQuantity) -> Self::Output {
+            Self {
+                value: self.value * rhs.value,
+                dimension: self.dimension * rhs.dimension,
+                scale: self.scale * rhs.scale,
+                // Like `PositionRad * Millimeters = PositionMM`
+                position: self.position != rhs.position
+            }
+        }
+    }
+
+    impl Div<DynQuantity> for DynQuantity {
+        type Output = DynQuantity;
+
+        #[inline]
+        fn div(self, rhs : DynQuantity) -> Self::Output {
+            Self {
+                value: self.value / rhs.value,
+                dimension: self.dimension / rhs.dimension,
+                scale: self.scale / rhs.scale,
+                // Like `PositionMM / Millimeters = PositionRad` and `PositionMM / PositionRad = Millimeters`
+                position: self.position != rhs.position
+            }
+        }
+    }
+
+    impl Mul<f32> for DynQuantity {
+        type Output = DynQuantity;
+
+        #[inline]
+        fn mul(self, rhs : f32) -> Self::Output {
+            Self { value: self.value * rhs, ..self }
+        }
+    }
+
+    impl Div<f32> for DynQuantity {
+        type Output = DynQuantity;
+
+        #[inline]
+        fn div(self, rhs : f32) -> Self::Output {
+            Self { value: self.value / rhs, ..self }
+        }
+    }
+
+    impl Neg for DynQuantity {
+        type Output = DynQuantity;
+
+        #[inline]
+        fn neg(self) -> Self::Output {
+            Self { value: -self.value, ..self }
+        }
+    }
+
+    impl FromStr for DynQuantity {
+        type Err = ParseDynQuantityError;
+
+        /// Parses a quantity out of a number followed by an optional symbol, e.g. `12.5mm` or `3 rad/s`
+        ///
+        /// A number without a symbol is parsed as a dimensionless quantity. Parsed quantities are always distances, see
+        /// [DynQuantity::with_position]
+        fn from_str(s : &str) -> Result<Self, Self::Err> {
+            let s = s.trim();
+
+            // Find the longest prefix that is a valid number
+            let (value, symbol) = s.char_indices()
+                .map(|(index, c)| index + c.len_utf8())
+                .rev()
+                .find_map(|split| s[..split].parse::<f32>().ok().map(|value| (value, s[split..].trim())))
+                .ok_or(ParseDynQuantityError::InvalidNumber)?;
+
+            if symbol.is_empty() {
+                return Ok(Self::new(value, Dimension::NONE, 1.0));
+            }
+
+            crate::UnitInfo::by_symbol(symbol)
+                .map(|info| Self::new(value, info.dimension, info.scale_to_si))
+                .ok_or(ParseDynQuantityError::UnknownSymbol)
+        }
+    }
+
+    impl Display for DynQuantity {
+        /// Writes the value followed by the symbol of the unit, if the unit is unknown the value is written in SI units
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(format!("{}", DynQuantity::from(MMPerSecond(2.0))), "2mm/s");
+        /// assert_eq!(format!("{}", DynQuantity::from(Millimeters(2.0)) * DynQuantity::from(Newtons(3.0))), "0.006kg*m^2/s^2");
+        /// assert_eq!(format!("{:#.1}", DynQuantity::from(MMPerSecond(2.0))), "2.0 mm/s");
+        /// ```
+        fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+            if let Some(symbol) = self.symbol() {
+                crate::format::write_unit(f, self.value, symbol)
+            } else if self.dimension == Dimension::NONE {
+                crate::format::write_unit(f, self.si_value(), "")
+            } else {
+                let mut symbol = crate::format::Buffer::new();
+                write!(symbol, "{}", self.dimension)?;
+                crate::format::write_unit(f, self.si_value(), symbol.as_str())
+            }
+        }
+    }
+//
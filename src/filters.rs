@@ -0,0 +1,330 @@
+//! All filters keep the unit of their samples, so a filter of [Newtons](crate::metric::Newtons) returns [Newtons](crate::metric::Newtons)
+//! again. They work with a fixed sample time, do not allocate and can be used in `no_std` environments. Filters that smooth their
+//! input ([LowPass], [MovingAverage] and [Biquad]) are initialized with the first sample, so position units can be filtered without
+//! a transient from zero.
+
+use core::f32::consts::TAU;
+use core::marker::PhantomData;
+use core::ops::Sub;
+
+use crate::{DerivableUnit, Hertz, Seconds, Unit};
+
+// #################
+// #    LowPass    #
+// #################
+    /// First order low-pass filter, configured by its cutoff frequency and the sample time
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::filters::LowPass;
+    ///
+    /// let mut filter = LowPass::new(Hertz(10.0), Seconds(0.001));
+    ///
+    /// // Initialized with the first sample
+    /// assert_eq!(filter.update(Newtons(2.0)), Newtons(2.0));
+    ///
+    /// // A step only passes partially
+    /// let force = filter.update(Newtons(4.0));
+    /// assert!(Newtons(2.0) < force && force < Newtons(2.2));
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct LowPass<U : Unit> {
+        alpha : f32,
+        value : Option<U>
+    }
+
+    impl<U : Unit> LowPass<U> {
+        /// Creates a new low-pass filter with the given `cutoff` frequency for samples taken every `sample_time`
+        pub fn new(cutoff : Hertz, sample_time : Seconds) -> Self {
+            let omega = TAU * (cutoff * sample_time);
+
+            Self {
+                alpha: omega / (1.0 + omega),
+                value: None
+            }
+        }
+
+        /// The smoothing factor of the filter, the part of a new sample that passes through in one step
+        pub fn alpha(&self) -> f32 {
+            self.alpha
+        }
+
+        /// The current output of the filter, `None` if no sample has been given yet
+        pub fn value(&self) -> Option<U> {
+            self.value
+        }
+
+        /// Resets the filter, the next sample initializes it again
+        pub fn reset(&mut self) {
+            self.value = None;
+        }
+
+        /// Adds a new `sample` to the filter, returning the new output
+        pub fn update(&mut self, sample : U) -> U {
+            let value = match self.value {
+                Some(value) => {
+                    let value : f32 = value.into();
+                    U::from(value + (sample.into() - value) * self.alpha)
+                },
+                None => sample
+            };
+
+            self.value = Some(value);
+            value
+        }
+    }
+//
+
+// #######################
+// #    MovingAverage    #
+// #######################
+    /// Moving average over the last `N` samples
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::filters::MovingAverage;
+    ///
+    /// let mut filter = MovingAverage::<MMPerSecond, 3>::new();
+    ///
+    /// // Averages the samples given so far until the filter is filled
+    /// assert_eq!(filter.update(MMPerSecond(3.0)), MMPerSecond(3.0));
+    /// assert_eq!(filter.update(MMPerSecond(6.0)), MMPerSecond(4.5));
+    /// assert_eq!(filter.update(MMPerSecond(9.0)), MMPerSecond(6.0));
+    ///
+    /// // The oldest sample gets replaced
+    /// assert_eq!(filter.update(MMPerSecond(12.0)), MMPerSecond(9.0));
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct MovingAverage<U : Unit, const N : usize> {
+        samples : [f32; N],
+        index : usize,
+        count : usize,
+        _unit : PhantomData<U>
+    }
+
+    impl<U : Unit, const N : usize> MovingAverage<U, N> {
+        /// Creates a new empty moving average, fails to compile if `N` is zero
+        pub const fn new() -> Self {
+            const { assert!(N > 0, "A moving average requires at least one sample") };
+
+            Self {
+                samples: [0.0; N],
+                index: 0,
+                count: 0,
+                _unit: PhantomData
+            }
+        }
+
+        /// The number of samples currently averaged, at most `N`
+        pub fn len(&self) -> usize {
+            self.count
+        }
+
+        /// Returns `true` if no sample has been given yet
+        pub fn is_empty(&self) -> bool {
+            self.count == 0
+        }
+
+        /// The current average, `None` if no sample has been given yet
+        pub fn value(&self) -> Option<U> {
+            if self.count == 0 {
+                None
+            } else {
+                Some(U::from(self.samples[..self.count].iter().sum::<f32>() / self.count as f32))
+            }
+        }
+
+        /// Removes all samples from the filter
+        pub fn reset(&mut self) {
+            self.index = 0;
+            self.count = 0;
+        }
+
+        /// Adds a new `sample` to the filter, replacing the oldest one if the filter is full, and returns the new average
+        pub fn update(&mut self, sample : U) -> U {
+            self.samples[self.index] = sample.into();
+            self.index = (self.index + 1) % N;
+            self.count = (self.count + 1).min(N);
+
+            // Summing up all samples again avoids accumulating rounding errors in a running sum
+            U::from(self.samples[..self.count].iter().sum::<f32>() / self.count as f32)
+        }
+    }
+
+    impl<U : Unit, const N : usize> Default for MovingAverage<U, N> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+//
+
+// ################
+// #    Biquad    #
+// ################
+    /// Second order IIR filter (biquad) in transposed direct form II
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::filters::Biquad;
+    ///
+    /// // Suppressing a resonance of the mechanics at 50Hz
+    /// let mut filter = Biquad::notch(Hertz(50.0), 2.0, Seconds(0.001));
+    ///
+    /// // Constant values pass unchanged
+    /// for _ in 0 .. 10 {
+    ///     assert!((filter.update(PositionMM(5.0)) - PositionMM(5.0)).abs() < Millimeters(1e-5));
+    /// }
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct Biquad<U : Unit> {
+        b : [f32; 3],
+        a : [f32; 2],
+        state : Option<[f32; 2]>,
+        _unit : PhantomData<U>
+    }
+
+    impl<U : Unit> Biquad<U> {
+        /// Creates a new biquad out of the coefficients of the transfer function
+        ///
+        /// ```text
+        /// H(z) = (b0 + b1 * z^-1 + b2 * z^-2) / (a0 + a1 * z^-1 + a2 * z^-2)
+        /// ```
+        ///
+        /// The coefficients are normalized by `a0`
+        pub fn new(b : [f32; 3], a : [f32; 3]) -> Self {
+            Self {
+                b: b.map(|b| b / a[0]),
+                a: [ a[1] / a[0], a[2] / a[0] ],
+                state: None,
+                _unit: PhantomData
+            }
+        }
+
+        /// Angular frequency and bandwidth term of the given `freq` and quality factor `q`
+        fn omega(freq : Hertz, q : f32, sample_time : Seconds) -> (f32, f32) {
+            let (sin, cos) = libm::sincosf(TAU * (freq * sample_time));
+            (cos, sin / (2.0 * q))
+        }
+
+        /// Second order low-pass filter with the given `cutoff` frequency and quality factor `q` (`0.7071` for a Butterworth filter)
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// use syunit::filters::Biquad;
+        ///
+        /// let mut filter = Biquad::low_pass(Hertz(10.0), 0.7071, Seconds(0.001));
+        ///
+        /// // Starts at the first sample
+        /// assert!((filter.update(Newtons(1.0)) - Newtons(1.0)).abs() < Newtons(1e-4));
+        /// assert!(filter.update(Newtons(2.0)) < Newtons(1.01));
+        /// ```
+        pub fn low_pass(cutoff : Hertz, q : f32, sample_time : Seconds) -> Self {
+            let (cos, alpha) = Self::omega(cutoff, q, sample_time);
+
+            Self::new(
+                [ (1.0 - cos) / 2.0, 1.0 - cos, (1.0 - cos) / 2.0 ],
+                [ 1.0 + alpha, -2.0 * cos, 1.0 - alpha ]
+            )
+        }
+
+        /// Notch filter removing the frequency `center` with the quality factor `q`, higher values of `q` give a narrower notch
+        pub fn notch(center : Hertz, q : f32, sample_time : Seconds) -> Self {
+            let (cos, alpha) = Self::omega(center, q, sample_time);
+
+            Self::new(
+                [ 1.0, -2.0 * cos, 1.0 ],
+                [ 1.0 + alpha, -2.0 * cos, 1.0 - alpha ]
+            )
+        }
+
+        /// Resets the filter, the next sample initializes it again
+        pub fn reset(&mut self) {
+            self.state = None;
+        }
+
+        /// Adds a new `sample` to the filter, returning the new output
+        pub fn update(&mut self, sample : U) -> U {
+            let [ b0, b1, b2 ] = self.b;
+            let [ a1, a2 ] = self.a;
+            let x : f32 = sample.into();
+
+            // Start in the steady state of the first sample
+            let [ z1, z2 ] = self.state.unwrap_or_else(|| {
+                let y = x * (b0 + b1 + b2) / (1.0 + a1 + a2);
+                [ y - b0 * x, b2 * x - a2 * y ]
+            });
+
+            let y = b0 * x + z1;
+            self.state = Some([ b1 * x - a1 * y + z2, b2 * x - a2 * y ]);
+
+            U::from(y)
+        }
+    }
+//
+
+// ########################
+// #    Differentiator    #
+// ########################
+    /// Numerical differentiator, turning a stream of samples into their rate of change using the [DerivableUnit] relations of the
+    /// difference between two samples
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::filters::Differentiator;
+    ///
+    /// let mut diff = Differentiator::<PositionMM>::new(Seconds(0.5));
+    ///
+    /// // No rate is known after the first sample
+    /// assert_eq!(diff.update(PositionMM(10.0)), MMPerSecond(0.0));
+    /// assert_eq!(diff.update(PositionMM(12.0)), MMPerSecond(4.0));
+    /// assert_eq!(diff.update(PositionMM(11.0)), MMPerSecond(-2.0));
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct Differentiator<U, T = Seconds>
+    where
+        U : Unit + Sub<U>,
+        <U as Sub<U>>::Output : DerivableUnit<T>,
+        T : Unit
+    {
+        sample_time : T,
+        last : Option<U>,
+        rate : <<U as Sub<U>>::Output as DerivableUnit<T>>::Result
+    }
+
+    impl<U, T> Differentiator<U, T>
+    where
+        U : Unit + Sub<U>,
+        <U as Sub<U>>::Output : DerivableUnit<T>,
+        T : Unit
+    {
+        /// Creates a new differentiator for samples taken every `sample_time`
+        pub fn new(sample_time : T) -> Self {
+            Self {
+                sample_time,
+                last: None,
+                rate: <<U as Sub<U>>::Output as DerivableUnit<T>>::Result::ZERO
+            }
+        }
+
+        /// The last calculated rate of change
+        pub fn rate(&self) -> <<U as Sub<U>>::Output as DerivableUnit<T>>::Result {
+            self.rate
+        }
+
+        /// Resets the differentiator, the next sample has no rate again
+        pub fn reset(&mut self) {
+            self.last = None;
+            self.rate = <<U as Sub<U>>::Output as DerivableUnit<T>>::Result::ZERO;
+        }
+
+        /// Adds a new `sample`, returning the rate of change since the last sample (zero for the first sample)
+        pub fn update(&mut self, sample : U) -> <<U as Sub<U>>::Output as DerivableUnit<T>>::Result {
+            if let Some(last) = self.last {
+                self.rate = (sample - last) / self.sample_time;
+            }
+
+            self.last = Some(sample);
+            self.rate
+        }
+    }
+//
@@ -0,0 +1,78 @@
+use core::cmp::Ordering;
+
+use crate::Unit;
+
+// ####################
+// #    FiniteUnit    #
+// ####################
+    /// A unit that is guaranteed to be [finite](Unit::is_finite), meaning it is neither [NaN](Unit::NAN) nor [Infinite](Unit::INFINITY)
+    ///
+    /// As the value is checked when constructing it, the wrapper implements [Eq] and [Ord]
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    ///
+    /// let mut feed_rates = [
+    ///     FiniteUnit::new(MMPerSecond(5.0)).unwrap(),
+    ///     FiniteUnit::new(MMPerSecond(-2.0)).unwrap(),
+    ///     FiniteUnit::new(MMPerSecond(3.0)).unwrap()
+    /// ];
+    /// feed_rates.sort();
+    ///
+    /// assert_eq!(feed_rates[0].get(), MMPerSecond(-2.0));
+    /// assert_eq!(feed_rates.iter().max().unwrap().get(), MMPerSecond(5.0));
+    ///
+    /// // Invalid values
+    /// assert!(FiniteUnit::new(MMPerSecond::NAN).is_none());
+    /// assert!(FiniteUnit::new(MMPerSecond(1.0) / 0.0).is_none());
+    /// ```
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct FiniteUnit<U : Unit>(U);
+
+    impl<U : Unit> FiniteUnit<U> {
+        /// Creates a new `FiniteUnit`, will be `None` if `unit` is not [finite](Unit::is_finite)
+        #[inline]
+        pub fn new(unit : U) -> Option<Self> {
+            unit.finite().map(Self)
+        }
+
+        /// Creates a new `FiniteUnit` without checking the value
+        ///
+        /// # Safety
+        ///
+        /// A `NaN` or infinite value breaks the guarantees of [Eq] and [Ord]
+        ///
+        /// Should only be used for creating literals
+        #[inline]
+        pub const unsafe fn new_unchecked(unit : U) -> Self {
+            Self(unit)
+        }
+
+        /// Returns the inner unit
+        #[inline]
+        pub fn get(self) -> U {
+            self.0
+        }
+    }
+
+    impl<U : Unit> Eq for FiniteUnit<U> { }
+
+    impl<U : Unit> PartialOrd for FiniteUnit<U> {
+        fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<U : Unit> Ord for FiniteUnit<U> {
+        fn cmp(&self, other : &Self) -> Ordering {
+            self.0.partial_cmp(&other.0)
+                .expect("FiniteUnit values are always comparable")
+        }
+    }
+
+    impl<U : Unit> core::fmt::Display for FiniteUnit<U> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Display::fmt(&self.0, f)
+        }
+    }
+//
@@ -0,0 +1,335 @@
+use core::fmt::{Alignment, Display, Formatter, Write};
+
+use crate::{Dimension, Unit, UNITS};
+
+// ################
+// #    Buffer    #
+// ################
+    /// Size of the stack buffer used to measure formatted units before padding them
+    const BUFFER_SIZE : usize = 128;
+
+    /// Small stack buffer, allowing `no_std` formatting of a unit before it gets padded
+    pub(crate) struct Buffer {
+        data : [u8; BUFFER_SIZE],
+        len : usize
+    }
+
+    impl Buffer {
+        pub(crate) const fn new() -> Self {
+            Self { data: [0; BUFFER_SIZE], len: 0 }
+        }
+
+        pub(crate) fn as_str(&self) -> &str {
+            // Only ever filled by `write_str`, so the content is always valid UTF-8
+            core::str::from_utf8(&self.data[..self.len]).unwrap_or_default()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s : &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+
+            if end > BUFFER_SIZE {
+                return Err(core::fmt::Error);
+            }
+
+            self.data[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+//
+
+// ####################
+// #    Formatting    #
+// ####################
+    /// Writes the `value` with the precision and sign flags of `f`
+    fn write_number<W : Write>(w : &mut W, value : f32, precision : Option<usize>, sign_plus : bool) -> core::fmt::Result {
+        match (precision, sign_plus) {
+            (Some(prec), true) => write!(w, "{:+.*}", prec, value),
+            (Some(prec), false) => write!(w, "{:.*}", prec, value),
+            (None, true) => write!(w, "{:+}", value),
+            (None, false) => write!(w, "{}", value)
+        }
+    }
+
+    /// Writes the `value` followed by all the `parts` of its symbol, respecting all the flags of the formatter `f`
+    fn write_parts(f : &mut Formatter<'_>, value : f32, parts : &[&str]) -> core::fmt::Result {
+        let precision = f.precision();
+        let sign_plus = f.sign_plus();
+        let spaced = f.alternate() && parts.iter().any(|part| !part.is_empty());
+
+        let mut buffer = Buffer::new();
+        let buffered = write_number(&mut buffer, value, precision, sign_plus)
+            .and_then(|_| if spaced { buffer.write_char(' ') } else { Ok(()) })
+            .and_then(|_| parts.iter().try_for_each(|part| buffer.write_str(part)));
+
+        // Too long for the buffer, write the unit without padding
+        if buffered.is_err() {
+            write_number(f, value, precision, sign_plus)?;
+
+            if spaced {
+                f.write_char(' ')?;
+            }
+
+            return parts.iter().try_for_each(|part| f.write_str(part));
+        }
+
+        let text = buffer.as_str();
+        let len = text.chars().count();
+
+        let padding = match f.width() {
+            Some(width) if width > len => width - len,
+            _ => return f.write_str(text)
+        };
+
+        if f.sign_aware_zero_pad() {
+            let (sign, rest) = if text.starts_with(['+', '-']) {
+                text.split_at(1)
+            } else {
+                ("", text)
+            };
+
+            f.write_str(sign)?;
+
+            for _ in 0 .. padding {
+                f.write_char('0')?;
+            }
+
+            return f.write_str(rest);
+        }
+
+        let (pre, post) = match f.align() {
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (padding, 0)
+        };
+        let fill = f.fill();
+
+        for _ in 0 .. pre {
+            f.write_char(fill)?;
+        }
+
+        f.write_str(text)?;
+
+        for _ in 0 .. post {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the `value` followed by the `symbol` of a unit into the formatter `f`
+    ///
+    /// All formatter options are respected: precision and sign are forwarded to the value, width, fill, alignment and
+    /// zero-padding apply to the full "value + symbol" string and the alternate form (`{:#}`) adds a space before the symbol
+    ///
+    /// Used by the `Display` implementations generated by [basic_unit!](crate::basic_unit)
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    ///
+    /// assert_eq!(format!("{:.2}", Millimeters(1.23456)), "1.23mm");
+    /// assert_eq!(format!("{:>8}", Millimeters(1.5)), "   1.5mm");
+    /// assert_eq!(format!("{:#}", Millimeters(1.5)), "1.5 mm");
+    /// ```
+    pub fn write_unit(f : &mut Formatter<'_>, value : f32, symbol : &str) -> core::fmt::Result {
+        write_parts(f, value, &[ symbol ])
+    }
+//
+
+// #####################
+// #    Engineering    #
+// #####################
+    /// All SI prefixes with their decimal exponent, from largest to smallest
+    pub const SI_PREFIXES : [(&str, i32); 21] = [
+        ("Q", 30), ("R", 27), ("Y", 24), ("Z", 21), ("E", 18), ("P", 15), ("T", 12), ("G", 9), ("M", 6), ("k", 3),
+        ("", 0),
+        ("m", -3), ("µ", -6), ("n", -9), ("p", -12), ("f", -15), ("a", -18), ("z", -21), ("y", -24), ("r", -27), ("q", -30)
+    ];
+
+    /// Display adapter writing a unit in its SI form with an automatically chosen SI prefix, e.g. `12µm` or `12MHz`
+    ///
+    /// The value is converted into the coherent SI unit of the same [Dimension] using [Unit::SCALE_TO_SI], its symbol is taken
+    /// from the [UNITS] registry or built from the dimension. The prefix is chosen to keep the value in the range `1..1000`, for
+    /// squared or cubed symbols like `m^2` the prefix is applied to the base unit (`1mm^2 = 1e-6m^2`). Units whose symbol starts
+    /// with `kg` are never prefixed.
+    ///
+    /// The written value is rounded to the 7 significant digits of an [f32], all formatter flags are respected, see [write_unit]
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    ///
+    /// assert_eq!(format!("{}", Millimeters(0.012).engineering()), "12µm");
+    /// assert_eq!(format!("{}", Hertz(12_000_000.0).engineering()), "12MHz");
+    /// assert_eq!(format!("{}", Newtons(2500.0).engineering()), "2.5kN");
+    /// assert_eq!(format!("{}", MMPerSecond(1500.0).engineering()), "1.5m/s");
+    /// assert_eq!(format!("{}", Millimeters2(4.0).engineering()), "4mm^2");
+    /// assert_eq!(format!("{}", syunit::imperial::Inches(1.0).engineering()), "25.4mm");
+    ///
+    /// // Precision, width and the alternate form
+    /// assert_eq!(format!("{:.3}", Seconds(0.0125).engineering()), "12.500ms");
+    /// assert_eq!(format!("{:#}", Hertz(12_000_000.0).engineering()), "12 MHz");
+    /// assert_eq!(format!("{:>8}", Seconds(0.0125).engineering()), "  12.5ms");
+    /// ```
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct Engineering<U : Unit>(pub U);
+
+    impl<U : Unit> Engineering<U> {
+        /// Returns the inner unit
+        #[inline]
+        pub fn get(self) -> U {
+            self.0
+        }
+    }
+
+    /// Returns the exponent of the first factor of the `symbol`, e.g. `2` for `m^2/s^2`
+    fn first_exponent(symbol : &str) -> Option<i32> {
+        let factor = symbol.split(['*', '/']).next().unwrap_or_default();
+
+        match factor.split_once('^') {
+            Some((_, exp)) => exp.parse().ok(),
+            None => Some(1)
+        }
+    }
+
+    /// Rounds the `value` to the 7 significant digits an [f32] can hold, hiding the representation error amplified by scaling
+    fn round_significant(value : f64) -> f32 {
+        if (value == 0.0) || !value.is_finite() {
+            return value as f32;
+        }
+
+        let mut magnitude = 1.0;
+        let mut digits = 0;
+
+        while value.abs() >= magnitude * 10.0 {
+            magnitude *= 10.0;
+            digits += 1;
+        }
+
+        while value.abs() < magnitude {
+            magnitude /= 10.0;
+            digits -= 1;
+        }
+
+        let factor = libm::pow(10.0, (6 - digits) as f64);
+        (libm::round(value * factor) / factor) as f32
+    }
+
+    impl<U : Unit> Display for Engineering<U> {
+        fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+            let si_value = <U as Into<f32>>::into(self.0) as f64 * U::SCALE_TO_SI;
+
+            // Searching for the symbol of the coherent SI unit
+            let mut dim_buffer = Buffer::new();
+            let symbol = if U::SCALE_TO_SI == 1.0 {
+                U::SYMBOL
+            } else if let Some(info) = UNITS.iter().find(|info| (info.dimension == U::DIMENSION) && (info.scale_to_si == 1.0)) {
+                info.symbol
+            } else if U::DIMENSION == Dimension::NONE {
+                ""
+            } else {
+                write!(dim_buffer, "{}", U::DIMENSION)?;
+                dim_buffer.as_str()
+            };
+
+            let exponent = match first_exponent(symbol) {
+                Some(exp) if (1 ..= 3).contains(&exp) && !symbol.is_empty() && !symbol.starts_with("kg") => exp,
+                _ => return write_parts(f, round_significant(si_value), &[ symbol ])
+            };
+
+            if (si_value == 0.0) || !si_value.is_finite() {
+                return write_parts(f, round_significant(si_value), &[ symbol ]);
+            }
+
+            let abs = si_value.abs();
+            let (prefix, scale) = SI_PREFIXES.iter()
+                .map(|(prefix, exp)| (*prefix, libm::pow(10.0, (exp * exponent) as f64)))
+                .find(|(_, scale)| abs >= *scale)
+                .unwrap_or(("q", libm::pow(10.0, (-30 * exponent) as f64)));
+
+            write_parts(f, round_significant(si_value / scale), &[ prefix, symbol ])
+        }
+    }
+//
+
+// ###############
+// #    defmt    #
+// ###############
+    /// Writes the `value` followed by the `symbol` of a unit using [defmt]
+    ///
+    /// Used by the [defmt::Format] implementations generated by [basic_unit!](crate::basic_unit)
+    #[cfg(feature = "defmt")]
+    pub fn defmt_unit(f : defmt::Formatter<'_>, value : f32, symbol : &str) {
+        defmt::write!(f, "{=f32}{=str}", value, symbol)
+    }
+//
+
+// ##############
+// #    ufmt    #
+// ##############
+    /// Number of decimal places written by [ufmt_f32]
+    #[cfg(feature = "ufmt")]
+    const UFMT_DECIMALS : u32 = 6;
+
+    /// Writes an [f32] using [ufmt], which does not support floats by itself
+    ///
+    /// The value is written with up to 6 decimal places, trailing zeros are removed
+    #[cfg(feature = "ufmt")]
+    pub fn ufmt_f32<W : ufmt::uWrite + ?Sized>(f : &mut ufmt::Formatter<'_, W>, value : f32) -> Result<(), W::Error> {
+        if value.is_nan() {
+            return f.write_str("NaN");
+        }
+
+        if value.is_infinite() {
+            return f.write_str(if value < 0.0 { "-inf" } else { "inf" });
+        }
+
+        let abs = value.abs();
+
+        // Every finite f32 fits into an u128, the fraction is scaled into an integer and rounded by adding a half before truncating
+        let scale = 10u32.pow(UFMT_DECIMALS);
+        let mut int = abs as u128;
+        let mut frac = ((abs as f64 - int as f64) * scale as f64 + 0.5) as u32;
+
+        if frac >= scale {
+            int += 1;
+            frac = 0;
+        }
+
+        // Values rounding to zero are written without sign
+        if (value < 0.0) && ((int != 0) || (frac != 0)) {
+            f.write_char('-')?;
+        }
+
+        ufmt::uDisplay::fmt(&int, f)?;
+
+        if frac == 0 {
+            return Ok(());
+        }
+
+        let mut digits = UFMT_DECIMALS;
+
+        while frac.is_multiple_of(10) {
+            frac /= 10;
+            digits -= 1;
+        }
+
+        f.write_char('.')?;
+
+        for pos in (0 .. digits).rev() {
+            f.write_char(char::from(b'0' + (frac / 10u32.pow(pos) % 10) as u8))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the `value` followed by the `symbol` of a unit using [ufmt], see [ufmt_f32]
+    ///
+    /// Used by the [ufmt::uDisplay] implementations generated by [basic_unit!](crate::basic_unit)
+    #[cfg(feature = "ufmt")]
+    pub fn ufmt_unit<W : ufmt::uWrite + ?Sized>(f : &mut ufmt::Formatter<'_, W>, value : f32, symbol : &str) -> Result<(), W::Error> {
+        ufmt_f32(f, value)?;
+        f.write_str(symbol)
+    }
+//
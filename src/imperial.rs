@@ -1,3 +1,4 @@
+use crate::Dimension;
 use crate::metric::Millimeters;
 
 #[cfg(feature = "serde")]
@@ -9,7 +10,10 @@ use crate as syunit;
     /// Represents metric meters
     #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+    #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+    #[repr(transparent)]
     pub struct Inches(pub f32);
-    syunit::basic_unit!(Inches, "in");
+    syunit::basic_unit!(Inches, "in", Dimension::LENGTH, 0.0254);
     syunit::impl_conversion!(Inches, Millimeters, 25.4); 
 // 
\ No newline at end of file
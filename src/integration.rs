@@ -0,0 +1,205 @@
+//! The functions work on series of samples taken with the constant sample time `dt`, the results are typed using the
+//! [IntegrableUnit] and [DerivableUnit] relations, e.g. integrating [MMPerSecond](crate::metric::MMPerSecond) samples over
+//! [Seconds] results in [Millimeters](crate::metric::Millimeters).
+//!
+//! ```rust
+//! use syunit::prelude::*;
+//! use syunit::integration;
+//!
+//! let velocities = [ MMPerSecond(0.0), MMPerSecond(2.0), MMPerSecond(4.0) ];
+//!
+//! assert_eq!(integration::euler(&velocities, Seconds(0.5)), Millimeters(1.0));
+//! assert_eq!(integration::trapezoid(&velocities, Seconds(0.5)), Millimeters(2.0));
+//! assert_eq!(integration::simpson(&velocities, Seconds(0.5)), Millimeters(2.0));
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Add, Sub};
+
+use crate::{DerivableUnit, IntegrableUnit, Seconds, Unit};
+
+// #####################
+// #    Integration    #
+// #####################
+    /// Integrates the `samples` using the (left) Euler method, each sample is held constant until the next one
+    ///
+    /// ```text
+    /// I = dt * (y0 + y1 + ... + y(n-2))
+    /// ```
+    ///
+    /// Returns zero for less than two samples
+    pub fn euler<U : IntegrableUnit<V>, V : Unit>(samples : &[U], dt : V) -> U::Result {
+        let sum : f32 = samples.iter().rev().skip(1).map(|&sample| sample.into()).sum();
+        U::from(sum) * dt
+    }
+
+    /// Integrates the `samples` using the trapezoidal rule
+    ///
+    /// ```text
+    /// I = dt * (y0 / 2 + y1 + ... + y(n-2) + y(n-1) / 2)
+    /// ```
+    ///
+    /// Returns zero for less than two samples
+    pub fn trapezoid<U : IntegrableUnit<V>, V : Unit>(samples : &[U], dt : V) -> U::Result {
+        let sum : f32 = samples.windows(2)
+            .map(|pair| (pair[0].into() + pair[1].into()) / 2.0)
+            .sum();
+
+        U::from(sum) * dt
+    }
+
+    /// Integrates the `samples` using Simpson's rule
+    ///
+    /// ```text
+    /// I = dt / 3 * (y0 + 4 * y1 + 2 * y2 + 4 * y3 + ... + 4 * y(n-2) + y(n-1))
+    /// ```
+    ///
+    /// Simpson's rule requires an odd number of samples, for an even number of samples the last interval is integrated using the
+    /// [trapezoidal rule](trapezoid). Returns zero for less than two samples
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::integration;
+    ///
+    /// // Exact for polynomials up to the third order, here v = t^2 with t = 0, 1, 2
+    /// let velocities = [ MMPerSecond(0.0), MMPerSecond(1.0), MMPerSecond(4.0) ];
+    /// assert_eq!(integration::simpson(&velocities, Seconds(1.0)), Millimeters(8.0 / 3.0));
+    /// ```
+    pub fn simpson<U : IntegrableUnit<V>, V : Unit>(samples : &[U], dt : V) -> U::Result {
+        if samples.len() < 3 {
+            return trapezoid(samples, dt);
+        }
+
+        // Use the largest odd number of samples for Simpson's rule
+        let count = if samples.len() % 2 == 1 { samples.len() } else { samples.len() - 1 };
+
+        let sum : f32 = samples[..count].iter()
+            .enumerate()
+            .map(|(index, &sample)| {
+                let weight = if index == 0 || index == count - 1 {
+                    1.0
+                } else if index % 2 == 1 {
+                    4.0
+                } else {
+                    2.0
+                };
+
+                weight * sample.into()
+            })
+            .sum();
+
+        let rest = if count < samples.len() {
+            (samples[count - 1].into() + samples[count].into()) / 2.0
+        } else {
+            0.0
+        };
+
+        U::from(sum / 3.0 + rest) * dt
+    }
+//
+
+// #########################
+// #    Differentiation    #
+// #########################
+    /// Differentiates the `samples` using forward differences, returning the `n - 1` rates between the samples
+    ///
+    /// The rate is calculated with the [DerivableUnit] relation of the difference between two samples, so both distances and
+    /// positions can be differentiated
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::integration;
+    ///
+    /// let positions = [ PositionMM(0.0), PositionMM(1.0), PositionMM(3.0) ];
+    /// let mut rates = integration::differentiate(&positions, Seconds(0.5));
+    ///
+    /// assert_eq!(rates.next(), Some(MMPerSecond(2.0)));
+    /// assert_eq!(rates.next(), Some(MMPerSecond(4.0)));
+    /// assert_eq!(rates.next(), None);
+    /// ```
+    pub fn differentiate<'a, U, V>(samples : &'a [U], dt : V) -> impl Iterator<Item = <<U as Sub<U>>::Output as DerivableUnit<V>>::Result> + 'a
+    where
+        U : Unit + Sub<U>,
+        <U as Sub<U>>::Output : DerivableUnit<V>,
+        V : Unit + 'a
+    {
+        samples.windows(2).map(move |pair| (pair[1] - pair[0]) / dt)
+    }
+//
+
+// ####################
+// #    Integrator    #
+// ####################
+    /// Stateful integrator, accumulating samples of `U` multiplied by the time passed into a value of `P`
+    ///
+    /// The integrator uses compensated (Neumaier) summation, so the rounding errors of adding many small increments to a large value
+    /// do not accumulate into a drift
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::integration::Integrator;
+    ///
+    /// let mut position = Integrator::new(PositionMM(0.0));
+    ///
+    /// // Moving with 0.1 mm/s for 1000 seconds, sampled every millisecond
+    /// for _ in 0 .. 1_000_000 {
+    ///     position.update(MMPerSecond(0.1), Seconds(0.001));
+    /// }
+    ///
+    /// assert!((position.value() - PositionMM(100.0)).abs() < Millimeters(1e-3));
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct Integrator<U, P, V = Seconds>
+    where
+        U : IntegrableUnit<V>,
+        P : Unit + Add<U::Result, Output = P>,
+        V : Unit
+    {
+        sum : f32,
+        compensation : f32,
+        _units : PhantomData<fn(U, V) -> P>
+    }
+
+    impl<U, P, V> Integrator<U, P, V>
+    where
+        U : IntegrableUnit<V>,
+        P : Unit + Add<U::Result, Output = P>,
+        V : Unit
+    {
+        /// Creates a new integrator starting at the given `value`
+        pub fn new(value : P) -> Self {
+            Self {
+                sum: value.into(),
+                compensation: 0.0,
+                _units: PhantomData
+            }
+        }
+
+        /// The current value of the integrator
+        pub fn value(&self) -> P {
+            P::from(self.sum + self.compensation)
+        }
+
+        /// Sets the integrator to the given `value`, discarding the accumulated rounding errors
+        pub fn reset(&mut self, value : P) {
+            self.sum = value.into();
+            self.compensation = 0.0;
+        }
+
+        /// Adds the `sample` held constant for the time `dt`, returning the new value
+        pub fn update(&mut self, sample : U, dt : V) -> P {
+            let increment : f32 = (sample * dt).into();
+            let sum = self.sum + increment;
+
+            // Keep track of the low order bits lost by the addition
+            if self.sum.abs() >= increment.abs() {
+                self.compensation += (self.sum - sum) + increment;
+            } else {
+                self.compensation += (increment - sum) + self.sum;
+            }
+
+            self.sum = sum;
+            self.value()
+        }
+    }
+//
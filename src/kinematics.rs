@@ -0,0 +1,266 @@
+use crate::{Unit, UnitSet};
+
+// ###############################
+// #    Constant acceleration    #
+// ###############################
+    /// Time required to travel the given `distance` while accelerating uniformly from `vel_start` to `vel_end`
+    ///
+    /// ```text
+    /// t = s / ((v0 + v1) / 2)
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(time_for_dist_accelerating::<MetricMM>(Millimeters(6.0), MMPerSecond(2.0), MMPerSecond(4.0)), Seconds(2.0));
+    /// assert_eq!(time_for_dist_accelerating::<Rotary>(Radians(3.0), RadPerSecond(2.0), RadPerSecond(4.0)), Seconds(1.0));
+    /// ```
+    pub fn time_for_dist_accelerating<U : UnitSet>(distance : U::Distance, vel_start : U::Velocity, vel_end : U::Velocity) -> U::Time {
+        distance / ((vel_start + vel_end) / 2.0)
+    }
+
+    /// Time required to reach the velocity `vel_end` from `vel_start` with the acceleration `acc`
+    ///
+    /// ```text
+    /// t = (v1 - v0) / a
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(time_for_velocity::<MetricMM>(MMPerSecond(2.0), MMPerSecond(8.0), MMPerSecond2(3.0)), Seconds(2.0));
+    /// assert_eq!(time_for_velocity::<Rotary>(RadPerSecond(8.0), RadPerSecond(2.0), RadPerSecond2(-2.0)), Seconds(3.0));
+    /// ```
+    pub fn time_for_velocity<U : UnitSet>(vel_start : U::Velocity, vel_end : U::Velocity, acc : U::Acceleration) -> U::Time {
+        (vel_end - vel_start) / acc
+    }
+
+    /// Distance travelled while accelerating from `vel_start` to `vel_end` with the acceleration `acc`
+    ///
+    /// ```text
+    /// s = (v1² - v0²) / (2 * a)
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(dist_for_velocity::<MetricMM>(MMPerSecond(2.0), MMPerSecond(4.0), MMPerSecond2(2.0)), Millimeters(3.0));
+    /// assert_eq!(dist_for_velocity::<Rotary>(RadPerSecond(4.0), RadPerSecond(2.0), RadPerSecond2(-2.0)), Radians(3.0));
+    /// ```
+    pub fn dist_for_velocity<U : UnitSet>(vel_start : U::Velocity, vel_end : U::Velocity, acc : U::Acceleration) -> U::Distance {
+        (vel_end - vel_start) * ((vel_end + vel_start) / acc) / 2.0
+    }
+
+    /// Distance travelled in the given `time` when starting with `vel_start` and accelerating with `acc`
+    ///
+    /// ```text
+    /// s = v0 * t + a * t² / 2
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(dist_for_time::<MetricMM>(MMPerSecond(1.0), MMPerSecond2(2.0), Seconds(3.0)), Millimeters(12.0));
+    /// ```
+    pub fn dist_for_time<U : UnitSet>(vel_start : U::Velocity, acc : U::Acceleration, time : U::Time) -> U::Distance {
+        vel_start * time + (acc * time) * time / 2.0
+    }
+
+    /// Velocity reached after the given `time` when starting with `vel_start` and accelerating with `acc`
+    ///
+    /// ```text
+    /// v1 = v0 + a * t
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(vel_after_time::<MetricMM>(MMPerSecond(1.0), MMPerSecond2(2.0), Seconds(3.0)), MMPerSecond(7.0));
+    /// ```
+    pub fn vel_after_time<U : UnitSet>(vel_start : U::Velocity, acc : U::Acceleration, time : U::Time) -> U::Velocity {
+        vel_start + acc * time
+    }
+
+    /// Velocity reached after travelling the given `distance` when starting with `vel_start` and accelerating with `acc`
+    ///
+    /// ```text
+    /// v1 = ±√(v0² + 2 * a * s)
+    /// ```
+    ///
+    /// The velocity points in the direction of the travelled `distance` (the direction of `vel_start` if the distance is zero). If the
+    /// velocity can never be reached (e.g. because the movement comes to a stop before travelling the full `distance`) the result is
+    /// [NaN](crate::Unit::NAN)
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(vel_after_dist::<MetricMM>(MMPerSecond(3.0), MMPerSecond2(2.0), Millimeters(4.0)), MMPerSecond(5.0));
+    /// assert_eq!(vel_after_dist::<Rotary>(RadPerSecond(-3.0), RadPerSecond2(-2.0), Radians(-4.0)), RadPerSecond(-5.0));
+    /// assert!(vel_after_dist::<MetricMM>(MMPerSecond(3.0), MMPerSecond2(-2.0), Millimeters(4.0)).is_nan());
+    ///
+    /// // Reversing movements end up in the direction of the distance
+    /// assert_eq!(vel_after_dist::<MetricMM>(MMPerSecond(3.0), MMPerSecond2(-2.0), Millimeters(-4.0)), MMPerSecond(-5.0));
+    /// ```
+    pub fn vel_after_dist<U : UnitSet>(vel_start : U::Velocity, acc : U::Acceleration, distance : U::Distance) -> U::Velocity {
+        // Sets do not have to provide a squared velocity, so the square is calculated with the values in the velocity unit,
+        // a * s = (a * t) * (s / t)
+        let time = U::Time::from(1.0);
+        let vel_start_val : f32 = vel_start.into();
+        let acc_val : f32 = (acc * time).into();
+        let dist_val : f32 = (distance / time).into();
+
+        let vel = U::Velocity::from(libm::sqrtf(vel_start_val * vel_start_val + 2.0 * acc_val * dist_val));
+
+        if (distance < U::Distance::ZERO) || ((distance == U::Distance::ZERO) && (vel_start < U::Velocity::ZERO)) {
+            -vel
+        } else {
+            vel
+        }
+    }
+
+    /// Distance required to come to a stop from the velocity `vel` with the (de-)acceleration `acc`
+    ///
+    /// ```text
+    /// s = v² / (2 * |a|)
+    /// ```
+    ///
+    /// The sign of the result matches the sign of `vel`, the sign of `acc` is ignored
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(braking_distance::<MetricMM>(MMPerSecond(4.0), MMPerSecond2(-2.0)), Millimeters(4.0));
+    /// assert_eq!(braking_distance::<Rotary>(RadPerSecond(-4.0), RadPerSecond2(2.0)), Radians(-4.0));
+    /// ```
+    pub fn braking_distance<U : UnitSet>(vel : U::Velocity, acc : U::Acceleration) -> U::Distance {
+        vel * (vel / acc.abs()).abs() / 2.0
+    }
+//
+
+// ######################
+// #    Jolt limited    #
+// ######################
+    /// Acceleration reached after the given `time` when starting with `acc_start` and a constant `jolt`
+    ///
+    /// ```text
+    /// a1 = a0 + j * t
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(acc_after_time_jolt::<MetricMM>(MMPerSecond2(1.0), MMPerSecond3(2.0), Seconds(3.0)), MMPerSecond2(7.0));
+    /// ```
+    pub fn acc_after_time_jolt<U : UnitSet>(acc_start : U::Acceleration, jolt : U::Jolt, time : U::Time) -> U::Acceleration {
+        acc_start + jolt * time
+    }
+
+    /// Time required to reach the acceleration `acc_end` from `acc_start` with the given `jolt`
+    ///
+    /// ```text
+    /// t = (a1 - a0) / j
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(time_for_acc_jolt::<MetricMM>(MMPerSecond2(1.0), MMPerSecond2(7.0), MMPerSecond3(2.0)), Seconds(3.0));
+    /// ```
+    pub fn time_for_acc_jolt<U : UnitSet>(acc_start : U::Acceleration, acc_end : U::Acceleration, jolt : U::Jolt) -> U::Time {
+        (acc_end - acc_start) / jolt
+    }
+
+    /// Velocity reached after the given `time` when starting with `vel_start`, `acc_start` and a constant `jolt`
+    ///
+    /// ```text
+    /// v1 = v0 + a0 * t + j * t² / 2
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(vel_after_time_jolt::<MetricMM>(MMPerSecond(1.0), MMPerSecond2(2.0), MMPerSecond3(2.0), Seconds(2.0)), MMPerSecond(9.0));
+    /// ```
+    pub fn vel_after_time_jolt<U : UnitSet>(vel_start : U::Velocity, acc_start : U::Acceleration, jolt : U::Jolt, time : U::Time) -> U::Velocity {
+        vel_start + acc_start * time + (jolt * time) * time / 2.0
+    }
+
+    /// Distance travelled in the given `time` when starting with `vel_start`, `acc_start` and a constant `jolt`
+    ///
+    /// ```text
+    /// s = v0 * t + a0 * t² / 2 + j * t³ / 6
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(dist_for_time_jolt::<MetricMM>(MMPerSecond(1.0), MMPerSecond2(2.0), MMPerSecond3(3.0), Seconds(2.0)), Millimeters(10.0));
+    /// ```
+    pub fn dist_for_time_jolt<U : UnitSet>(vel_start : U::Velocity, acc_start : U::Acceleration, jolt : U::Jolt, time : U::Time) -> U::Distance {
+        vel_start * time + (acc_start * time) * time / 2.0 + ((jolt * time) * time) * time / 6.0
+    }
+
+    /// Time required to change the velocity from `vel_start` to `vel_end` with a jolt limited (S-curve) profile,
+    /// starting and ending with zero acceleration
+    ///
+    /// The absolute acceleration is limited by `acc_max`, the absolute jolt by `jolt`. If the maximum acceleration cannot be reached,
+    /// the profile consists of two jolt phases only
+    ///
+    /// ```text
+    /// t = Δv / a + a / j      (if Δv >= a² / j)
+    /// t = 2 * √(Δv / j)       (otherwise)
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// // Maximum acceleration is reached
+    /// assert_eq!(time_for_velocity_jolt::<MetricMM>(MMPerSecond(0.0), MMPerSecond(8.0), MMPerSecond2(2.0), MMPerSecond3(1.0)), Seconds(6.0));
+    /// // Maximum acceleration is not reached
+    /// assert_eq!(time_for_velocity_jolt::<MetricMM>(MMPerSecond(0.0), MMPerSecond(1.0), MMPerSecond2(2.0), MMPerSecond3(1.0)), Seconds(2.0));
+    /// ```
+    pub fn time_for_velocity_jolt<U : UnitSet>(vel_start : U::Velocity, vel_end : U::Velocity, acc_max : U::Acceleration, jolt : U::Jolt) -> U::Time {
+        let vel_diff = (vel_end - vel_start).abs();
+        let acc_max = acc_max.abs();
+        let jolt = jolt.abs();
+
+        let time_acc = vel_diff / acc_max;
+        let time_jolt = acc_max / jolt;
+
+        if time_acc >= time_jolt {
+            time_acc + time_jolt
+        } else {
+            // Δv / j = (Δv / a) * (a / j), the root of the product of two times stays in the same time unit
+            U::Time::from(libm::sqrtf(time_acc.into() * time_jolt.into())) * 2.0
+        }
+    }
+
+    /// Distance travelled while changing the velocity from `vel_start` to `vel_end` with a jolt limited (S-curve) profile,
+    /// see [time_for_velocity_jolt]
+    ///
+    /// ```text
+    /// s = (v0 + v1) / 2 * t
+    /// ```
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit::kinematics::*;
+    ///
+    /// assert_eq!(dist_for_velocity_jolt::<MetricMM>(MMPerSecond(0.0), MMPerSecond(8.0), MMPerSecond2(2.0), MMPerSecond3(1.0)), Millimeters(24.0));
+    /// ```
+    pub fn dist_for_velocity_jolt<U : UnitSet>(vel_start : U::Velocity, vel_end : U::Velocity, acc_max : U::Acceleration, jolt : U::Jolt) -> U::Distance {
+        (vel_start + vel_end) / 2.0 * time_for_velocity_jolt::<U>(vel_start, vel_end, acc_max, jolt)
+    }
+//
@@ -4,10 +4,14 @@
 // Rules
 #![deny(missing_docs)]
 
+#[cfg(feature = "schemars")]
+extern crate alloc;
+
+use core::cmp::Ordering;
 use core::fmt::{Debug, Display};
+use core::iter::Sum;
 use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 use core::str::FromStr;
-use core::time::Duration;
 
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize}; 
@@ -15,17 +19,69 @@ use serde::{Serialize, Deserialize};
 // ####################
 // #    Submodules    #
 // ####################
+    /// Formatting helpers for units, like SI prefixed [engineering notation](format::Engineering)
+    pub mod format;
+
     /// Functions for handling units
     mod funcs;
     pub use funcs::*;
 
+    /// Conversions between unit arrays and the points and vectors of linear algebra libraries (`nalgebra` and `glam`)
+    pub mod linalg;
+
+    /// Kinematic equations for constant acceleration and jolt limited movements, generic over [UnitSets](UnitSet)
+    pub mod kinematics;
+
+    /// Typed controllers, like a [PID controller](control::Pid) with gains checked by the type system
+    pub mod control;
+
+    /// Unit preserving digital filters and numerical differentiators
+    pub mod filters;
+
+    /// Numerical integration and differentiation of sampled series, driven by [IntegrableUnit] and [DerivableUnit]
+    pub mod integration;
+
     /// Macros for creating units and fast implementations between them
     pub mod macros;
 
+    /// JSON schemas of units for configuration editors
+    #[cfg(feature = "schemars")]
+    pub mod schema;
+
+    /// Serialization of units as strings with their symbol, e.g. `"12.5mm"`
+    #[cfg(feature = "serde")]
+    pub mod serde_symbol;
+
+    /// Statistical helpers for iterators of units (mean, RMS, standard deviation, ...)
+    pub mod stats;
+
     // Special units
     mod specials;
     pub use specials::*;
 
+    // Runtime dimensions
+    mod dynamic;
+    pub use dynamic::*;
+
+    // Unit metadata
+    mod registry;
+    pub use registry::*;
+
+    // Duration conversions
+    mod duration;
+    pub use duration::*;
+
+    // Wrappers
+    mod finite;
+    pub use finite::*;
+
+    mod ord;
+    pub use ord::*;
+
+    // External crates
+    #[cfg(feature = "uom")]
+    mod uom_impl;
+
     // Unit systems
     /// Imperial units of measurement
     pub mod imperial;
@@ -44,9 +100,57 @@ use serde::{Serialize, Deserialize};
     }
 // 
 
+/// Derive macro for units, replacing the calls of [basic_unit!], [additive_unit!], [position_unit!] and [derive_units!]
+/// 
+/// See the [syunit_derive](https://docs.rs/syunit-derive) crate for all the options available
+#[cfg(feature = "derive")]
+pub use syunit_derive::Unit;
+
 // Helper import for local macro definitions
 use crate as syunit;
 
+/// Re-exports of optional dependencies used by the exported macros
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "schemars")]
+    pub use alloc::borrow::Cow;
+
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+
+    #[cfg(feature = "num-traits")]
+    pub use num_traits;
+
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+
+    #[cfg(feature = "ufmt")]
+    pub use ufmt;
+
+    /// Detects [PositionUnits](crate::PositionUnit) in [dyn_unit!](crate::dyn_unit), `<IsPosition<U>>::VALUE` is `true` for position
+    /// units and falls back to [NotPosition::VALUE] for all other units
+    pub struct IsPosition<U>(core::marker::PhantomData<U>);
+
+    impl<U : crate::PositionUnit> IsPosition<U> {
+        /// The unit is a position
+        pub const VALUE : bool = true;
+    }
+
+    /// Fallback of [IsPosition] for units that are not positions
+    pub trait NotPosition {
+        /// The unit is not a position
+        const VALUE : bool = false;
+    }
+
+    impl<U> NotPosition for IsPosition<U> { }
+}
+
 // ########################
 // #    General traits    #
 // ########################
@@ -56,7 +160,7 @@ use crate as syunit;
         Copy + Clone + Debug + Display + PartialEq + PartialOrd + Default + 
         FromStr + core::fmt::Debug + core::fmt::Display +
         Mul<f32, Output = Self> + Div<f32, Output = Self> + Div<Self, Output = f32> + Neg<Output = Self> +
-        Mul<Factor, Output = Self>
+        Mul<Factor, Output = Self> + Mul<Direction, Output = Self>
     where  
         Self : Sized
     { 
@@ -77,7 +181,95 @@ use crate as syunit;
         /// ```
         const NAN : Self;
 
-        /// Returns the absolute value of the unit 
+        /// Largest finite value of this unit (f32::MAX)
+        const MAX : Self;
+        /// Smallest finite value of this unit (f32::MIN)
+        const MIN : Self;
+
+        /// Symbol used when displaying the unit, e.g. `"mm"`, empty if the unit has no symbol
+        const SYMBOL : &'static str;
+        /// Name of the unit type, e.g. `"Millimeters"`
+        const NAME : &'static str;
+        /// Physical [Dimension] of the unit, [Dimension::NONE] for units defined without one
+        const DIMENSION : Dimension;
+        /// Scale of the unit, one unit equals `SCALE_TO_SI` SI units (m, kg, s, rad)
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Millimeters::SCALE_TO_SI, 0.001);
+        /// assert_eq!(Millimeters::DIMENSION, Dimension::LENGTH);
+        /// assert_eq!(Millimeters::SYMBOL, "mm");
+        /// assert_eq!(Millimeters::NAME, "Millimeters");
+        /// ```
+        const SCALE_TO_SI : f64;
+
+        /// Returns the value of the unit in SI base units, see [Unit::SCALE_TO_SI]
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Millimeters(2000.0).to_si(), 2.0);
+        /// ```
+        #[inline]
+        fn to_si(self) -> f32 {
+            (self.into() as f64 * Self::SCALE_TO_SI) as f32
+        }
+
+        /// Creates the unit from a value given in SI base units, see [Unit::SCALE_TO_SI]
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Millimeters::from_si(2.0), Millimeters(2000.0));
+        /// ```
+        #[inline]
+        fn from_si(value : f32) -> Self {
+            Self::from((value as f64 / Self::SCALE_TO_SI) as f32)
+        }
+
+        // Wire format
+            /// Returns the wire representation of the unit, its value as IEEE 754 single precision float in little-endian byte order
+            ///
+            /// This is the same encoding `postcard` uses for units, and on little-endian targets (ARM Cortex-M, RISC-V, x86) also
+            /// matches the in-memory layout exposed by the "bytemuck" and "zerocopy" features, as all units are `#[repr(transparent)]`
+            ///
+            /// ```rust
+            /// use syunit::prelude::*;
+            ///
+            /// assert_eq!(Millimeters(1.0).to_le_bytes(), [ 0x00, 0x00, 0x80, 0x3F ]);
+            /// ```
+            #[inline]
+            fn to_le_bytes(self) -> [u8; 4] {
+                self.into().to_le_bytes()
+            }
+
+            /// Creates a unit from its wire representation, see [Unit::to_le_bytes]
+            ///
+            /// ```rust
+            /// use syunit::prelude::*;
+            ///
+            /// assert_eq!(Millimeters::from_le_bytes([ 0x00, 0x00, 0x80, 0x3F ]), Millimeters(1.0));
+            /// ```
+            #[inline]
+            fn from_le_bytes(bytes : [u8; 4]) -> Self {
+                Self::from(f32::from_le_bytes(bytes))
+            }
+        //
+
+        /// Returns a display adapter writing the unit with an automatically chosen SI prefix, see [format::Engineering]
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(format!("{:#}", Seconds(0.000_25).engineering()), "250 µs");
+        /// ```
+        #[inline]
+        fn engineering(self) -> format::Engineering<Self> {
+            format::Engineering(self)
+        }
+
+        /// Returns the absolute value of the unit
         #[inline(always)]
         fn abs(self) -> Self {
             Self::from(self.into().abs())
@@ -102,38 +294,138 @@ use crate as syunit;
         }
 
         /// Returns the unit raised to the given integer power `pow`
+        /// 
+        /// The result is dimensionally wrong for every `pow` except `1`, see [SquareUnit], [CubeUnit] and [RootUnit] for typed alternatives
         #[inline(always)]
+        #[deprecated(note = "the result does not have the dimension of `Self`, use `SquareUnit::squared`, `CubeUnit::cubed` or `RootUnit::sqrt` instead")]
         fn powi(self, pow : i32) -> Self {
-            Self::from(self.into().powi(pow))
+            Self::from(libm::powf(self.into(), pow as f32))
         }
 
         /// Returns the unit raised to the given power `pow`
+        /// 
+        /// The result is dimensionally wrong for every `pow` except `1.0`, see [SquareUnit], [CubeUnit] and [RootUnit] for typed alternatives
         #[inline(always)]
+        #[deprecated(note = "the result does not have the dimension of `Self`, use `SquareUnit::squared`, `CubeUnit::cubed` or `RootUnit::sqrt` instead")]
         fn powf(self, pow : f32) -> Self {
-            Self::from(self.into().powf(pow))
+            Self::from(libm::powf(self.into(), pow))
         }
 
-        /// Returns the sin of this units value
-        #[inline(always)]
-        fn sin(self) -> f32 {
-            self.into().sin()
-        }
+        // Checked and saturating arithmetic
+            /// Returns `Some` if the value is [finite](Unit::is_finite), `None` otherwise
+            /// 
+            /// ```rust
+            /// use syunit::prelude::*;
+            /// 
+            /// assert_eq!(Millimeters(2.0).finite(), Some(Millimeters(2.0)));
+            /// assert_eq!(Millimeters::NAN.finite(), None);
+            /// assert_eq!(Millimeters::INFINITY.finite(), None);
+            /// ```
+            #[inline]
+            fn finite(self) -> Option<Self> {
+                if self.is_finite() {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
 
-        /// Returns the cos of this units value
-        #[inline(always)]
-        fn cos(self) -> f32 {
-            self.into().tan()
-        }
+            /// Returns `Ok` if the value is [finite](Unit::is_finite), the given error `err` otherwise
+            /// 
+            /// ```rust
+            /// use syunit::prelude::*;
+            /// 
+            /// assert_eq!(MMPerSecond(2.0).finite_or("invalid feed rate"), Ok(MMPerSecond(2.0)));
+            /// assert_eq!((MMPerSecond(2.0) / 0.0).finite_or("invalid feed rate"), Err("invalid feed rate"));
+            /// ```
+            #[inline]
+            fn finite_or<E>(self, err : E) -> Result<Self, E> {
+                self.finite().ok_or(err)
+            }
 
-        /// Returns the tan of this units value
-        #[inline(always)]
-        fn tan(self) -> f32 {
-            self.into().tan()
-        }
+            /// Multiplies the unit with `rhs`, returns `None` if the result is not [finite](Unit::is_finite)
+            /// 
+            /// ```rust
+            /// use syunit::prelude::*;
+            /// 
+            /// assert_eq!(Millimeters(2.0).checked_mul(3.0), Some(Millimeters(6.0)));
+            /// assert_eq!(Millimeters::MAX.checked_mul(2.0), None);
+            /// ```
+            #[inline]
+            fn checked_mul(self, rhs : f32) -> Option<Self> {
+                (self * rhs).finite()
+            }
+
+            /// Divides the unit by `rhs`, returns `None` if the result is not [finite](Unit::is_finite) (e.g. when dividing by zero)
+            /// 
+            /// ```rust
+            /// use syunit::prelude::*;
+            /// 
+            /// assert_eq!(Millimeters(6.0).checked_div(3.0), Some(Millimeters(2.0)));
+            /// assert_eq!(Millimeters(6.0).checked_div(0.0), None);
+            /// assert_eq!(Millimeters::ZERO.checked_div(0.0), None);
+            /// ```
+            #[inline]
+            fn checked_div(self, rhs : f32) -> Option<Self> {
+                (self / rhs).finite()
+            }
+
+            /// Clamps the value into the finite range from [Unit::MIN] to [Unit::MAX], [NaN](Unit::NAN) stays [NaN](Unit::NAN)
+            /// 
+            /// ```rust
+            /// use syunit::prelude::*;
+            /// 
+            /// assert_eq!(Millimeters::INFINITY.saturate(), Millimeters::MAX);
+            /// assert_eq!(Millimeters::NEG_INFINITY.saturate(), Millimeters::MIN);
+            /// assert!(Millimeters::NAN.saturate().is_nan());
+            /// ```
+            #[inline]
+            fn saturate(self) -> Self {
+                Self::from(self.into().clamp(f32::MIN, f32::MAX))
+            }
+
+            /// Multiplies the unit with `rhs`, saturating at [Unit::MIN] and [Unit::MAX] instead of overflowing
+            /// 
+            /// ```rust
+            /// use syunit::prelude::*;
+            /// 
+            /// assert_eq!(Millimeters(2.0).saturating_mul(3.0), Millimeters(6.0));
+            /// assert_eq!(Millimeters::MAX.saturating_mul(-2.0), Millimeters::MIN);
+            /// ```
+            #[inline]
+            fn saturating_mul(self, rhs : f32) -> Self {
+                (self * rhs).saturate()
+            }
+
+            /// Divides the unit by `rhs`, saturating at [Unit::MIN] and [Unit::MAX] instead of overflowing
+            /// 
+            /// ```rust
+            /// use syunit::prelude::*;
+            /// 
+            /// assert_eq!(Millimeters(6.0).saturating_div(3.0), Millimeters(2.0));
+            /// assert_eq!(Millimeters(6.0).saturating_div(0.0), Millimeters::MAX);
+            /// ```
+            #[inline]
+            fn saturating_div(self, rhs : f32) -> Self {
+                (self / rhs).saturate()
+            }
+        // 
 
         /// Get the direction of the value (positive or negative)
         /// 
-        /// `0.0` will be accounted as positive
+        /// `0.0` and `-0.0` will be accounted as positive, `NaN` as negative. Use [Unit::try_direction] to handle values without a
+        /// direction explicitly
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(Millimeters(2.0).get_direction(), Direction::CW);
+        /// assert_eq!(Millimeters(-2.0).get_direction(), Direction::CCW);
+        /// assert_eq!(Millimeters(0.0).get_direction(), Direction::CW);
+        /// assert_eq!(Millimeters(-0.0).get_direction(), Direction::CW);
+        /// assert_eq!(Millimeters::NAN.get_direction(), Direction::CCW);
+        /// ```
+        #[inline]
         fn get_direction(self) -> syunit::Direction {
             if self >= Self::ZERO {
                 syunit::Direction::CW
@@ -142,6 +434,14 @@ use crate as syunit;
             }
         }
 
+        /// Get the direction of the value, `None` if the value has no direction (`0.0`, `-0.0` and `NaN`)
+        /// 
+        /// See [Direction::from_sign]
+        #[inline]
+        fn try_direction(self) -> Option<syunit::Direction> {
+            syunit::Direction::from_sign(self.into())
+        }
+
         /// Returns `true` if the sign bit of this value is negative (value smaller than `0.0`, `-0.0` included)
         fn is_sign_negative(self) -> bool { 
             self.into().is_sign_negative()
@@ -152,6 +452,20 @@ use crate as syunit;
             self.into().is_sign_positive()
         }
 
+        /// Returns the sign of the value as dimensionless number, `1.0` if the sign is positive, `-1.0` if negative and `NaN` if
+        /// the value is `NaN`
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert_eq!(Millimeters(-2.0).signum(), -1.0);
+        /// assert_eq!(Millimeters(-2.0) * Millimeters(-2.0).signum(), Millimeters(2.0));
+        /// ```
+        #[inline]
+        fn signum(self) -> f32 {
+            self.into().signum()
+        }
+
         // Comparision
             /// Compare this unit with another instance and return the bigger one
             #[inline(always)]
@@ -165,6 +479,24 @@ use crate as syunit;
                 Self::from(self.into().min(other.into()))
             }
 
+            /// Compares this unit with another instance using the total ordering of [f32::total_cmp]
+            /// 
+            /// Unlike [PartialOrd], this ordering is defined for every value, making it useful for sorting
+            /// 
+            /// ```rust
+            /// use syunit::prelude::*;
+            /// 
+            /// let mut lengths = [ Millimeters(3.0), Millimeters(-1.0), Millimeters::NAN, Millimeters(2.0) ];
+            /// lengths.sort_by(Unit::total_cmp);
+            /// 
+            /// assert_eq!(&lengths[..3], &[ Millimeters(-1.0), Millimeters(2.0), Millimeters(3.0) ]);
+            /// assert!(lengths[3].is_nan());
+            /// ```
+            #[inline]
+            fn total_cmp(&self, other : &Self) -> Ordering {
+                (*self).into().total_cmp(&(*other).into())
+            }
+
             /// Like [Unit::max], but with references
             #[inline(always)]
             fn max_ref<'a>(&'a self, other : &'a Self) -> &'a Self {
@@ -188,10 +520,114 @@ use crate as syunit;
     }
 
     /// Marker traits for units, that can be added and subtracted from themselfs
+    /// 
+    /// Additive units can also be summed up using [Iterator::sum], both for owned and referenced items
+    /// 
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// 
+    /// let segments = [ Millimeters(2.0), Millimeters(3.5), Millimeters(-1.0) ];
+    /// 
+    /// assert_eq!(segments.iter().sum::<Millimeters>(), Millimeters(4.5));
+    /// assert_eq!(segments.into_iter().sum::<Millimeters>(), Millimeters(4.5));
+    /// ```
     pub trait AdditiveUnit : Unit +
         Add<Self, Output = Self> + Sub<Self, Output = Self> +
-        AddAssign<Self> + SubAssign<Self> { }
+        AddAssign<Self> + SubAssign<Self> +
+        Sum<Self> + for<'a> Sum<&'a Self>
+    { 
+        /// Adds `rhs` to the unit, returns `None` if the result is not [finite](Unit::is_finite)
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert_eq!(Millimeters(2.0).checked_add(Millimeters(3.0)), Some(Millimeters(5.0)));
+        /// assert_eq!(Millimeters::MAX.checked_add(Millimeters::MAX), None);
+        /// assert_eq!(Millimeters(2.0).checked_add(Millimeters::NAN), None);
+        /// ```
+        #[inline]
+        fn checked_add(self, rhs : Self) -> Option<Self> {
+            (self + rhs).finite()
+        }
+
+        /// Subtracts `rhs` from the unit, returns `None` if the result is not [finite](Unit::is_finite)
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert_eq!(Millimeters(2.0).checked_sub(Millimeters(3.0)), Some(Millimeters(-1.0)));
+        /// assert_eq!(Millimeters::MIN.checked_sub(Millimeters::MAX), None);
+        /// ```
+        #[inline]
+        fn checked_sub(self, rhs : Self) -> Option<Self> {
+            (self - rhs).finite()
+        }
+
+        /// Adds `rhs` to the unit, saturating at [Unit::MIN] and [Unit::MAX] instead of overflowing
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert_eq!(Millimeters(2.0).saturating_add(Millimeters(3.0)), Millimeters(5.0));
+        /// assert_eq!(Millimeters::MAX.saturating_add(Millimeters::MAX), Millimeters::MAX);
+        /// ```
+        #[inline]
+        fn saturating_add(self, rhs : Self) -> Self {
+            (self + rhs).saturate()
+        }
+
+        /// Subtracts `rhs` from the unit, saturating at [Unit::MIN] and [Unit::MAX] instead of overflowing
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert_eq!(Millimeters(2.0).saturating_sub(Millimeters(3.0)), Millimeters(-1.0));
+        /// assert_eq!(Millimeters::MIN.saturating_sub(Millimeters::MAX), Millimeters::MIN);
+        /// ```
+        #[inline]
+        fn saturating_sub(self, rhs : Self) -> Self {
+            (self - rhs).saturate()
+        }
+
+        /// Returns the positive difference between the unit and `other`, [Unit::ZERO] if `other` is bigger
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert_eq!(Millimeters(5.0).abs_sub(Millimeters(3.0)), Millimeters(2.0));
+        /// assert_eq!(Millimeters(3.0).abs_sub(Millimeters(5.0)), Millimeters::ZERO);
+        /// ```
+        #[inline]
+        fn abs_sub(self, other : Self) -> Self {
+            if self <= other {
+                Self::ZERO
+            } else {
+                self - other
+            }
+        }
+    }
     
+    /// An absolute position, which can only be moved by its relative [Distance](PositionUnit::Distance) unit
+    /// 
+    /// Subtracting two positions results in their distance
+    /// 
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// 
+    /// fn travel<P : PositionUnit>(from : P, to : P) -> P::Distance {
+    ///     to - from
+    /// }
+    /// 
+    /// assert_eq!(travel(PositionMM(2.0), PositionMM(5.0)), Millimeters(3.0));
+    /// ```
+    pub trait PositionUnit : Unit +
+        Add<Self::Distance, Output = Self> + Sub<Self::Distance, Output = Self> + Sub<Self, Output = Self::Distance> +
+        AddAssign<Self::Distance> + SubAssign<Self::Distance>
+    {
+        /// The relative distance unit of this position
+        type Distance : AdditiveUnit;
+    }
+
     /// Marker trait for units that can be derived by a variable `V` to form the result `Result`
     /// 
     /// ```text
@@ -305,6 +741,145 @@ use crate as syunit;
             Self::from(reduced.into() / ratio.into() / ratio.into())
         }
     }
+
+    /// Trait for units that can be squared, forming the unit `Squared`
+    /// 
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// 
+    /// assert_eq!(Millimeters(3.0).squared(), Millimeters2(9.0));
+    /// assert_eq!(MMPerSecond(3.0).squared(), MM2PerSecond2(9.0));
+    /// ```
+    pub trait SquareUnit : Unit + Mul<Self, Output = Self::Squared> {
+        /// The unit that is formed by squaring this unit
+        type Squared : Unit;
+
+        /// Returns the square of this unit
+        #[inline(always)]
+        fn squared(self) -> Self::Squared {
+            self * self
+        }
+    }
+
+    /// Trait for units that can be cubed, forming the unit `Cubed`
+    /// 
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// 
+    /// assert_eq!(Millimeters(2.0).cubed(), Millimeters3(8.0));
+    /// assert_eq!(Millimeters3(8.0) / Millimeters(2.0), Millimeters2(4.0));
+    /// ```
+    pub trait CubeUnit : SquareUnit + Mul<Self::Squared, Output = Self::Cubed> {
+        /// The unit that is formed by cubing this unit
+        type Cubed : Unit;
+
+        /// Returns the cube of this unit
+        #[inline(always)]
+        fn cubed(self) -> Self::Cubed {
+            self * self.squared()
+        }
+    }
+
+    /// Trait for units that have a square root, forming the unit `Root`
+    /// 
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// 
+    /// assert_eq!(Millimeters2(9.0).sqrt(), Millimeters(3.0));
+    /// 
+    /// // v = √(2 * a * s)
+    /// assert_eq!((MMPerSecond2(2.0) * Millimeters(4.0) * 2.0).sqrt(), MMPerSecond(4.0));
+    /// ```
+    pub trait RootUnit : Unit {
+        /// The unit that is formed by taking the square root of this unit
+        type Root : SquareUnit<Squared = Self>;
+
+        /// Returns the square root of this unit
+        /// 
+        /// Negative values result in [NaN](Unit::NAN)
+        #[inline(always)]
+        fn sqrt(self) -> Self::Root {
+            Self::Root::from(libm::sqrtf(self.into()))
+        }
+    }
+
+    /// Trait for units that represent an angle, giving access to trigonometric functions
+    /// 
+    /// The inverse functions are available on [Radians], e.g. [Radians::asin]
+    /// 
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// 
+    /// assert_eq!(Radians(0.0).sin(), 0.0);
+    /// assert_eq!(PositionRad(0.0).cos(), 1.0);
+    /// ```
+    pub trait AngleUnit : Unit + Into<Radians> {
+        /// Returns the angle in [Radians]
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert_eq!(PositionRad(2.0).to_radians(), Radians(2.0));
+        /// ```
+        #[inline(always)]
+        fn to_radians(self) -> Radians {
+            <Self as Into<Radians>>::into(self)
+        }
+
+        /// Returns the sine of the angle
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert!((Radians(core::f32::consts::FRAC_PI_2).sin() - 1.0).abs() < 1e-6);
+        /// assert!((PositionRad(-core::f32::consts::FRAC_PI_6).sin() + 0.5).abs() < 1e-6);
+        /// ```
+        #[inline(always)]
+        fn sin(self) -> f32 {
+            libm::sinf(self.to_radians().0)
+        }
+
+        /// Returns the cosine of the angle
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert!((Radians(core::f32::consts::PI).cos() + 1.0).abs() < 1e-6);
+        /// assert!((PositionRad(core::f32::consts::FRAC_PI_3).cos() - 0.5).abs() < 1e-6);
+        /// ```
+        #[inline(always)]
+        fn cos(self) -> f32 {
+            libm::cosf(self.to_radians().0)
+        }
+
+        /// Returns the tangent of the angle
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert!((Radians(core::f32::consts::FRAC_PI_4).tan() - 1.0).abs() < 1e-6);
+        /// assert!((PositionRad(-core::f32::consts::FRAC_PI_4).tan() + 1.0).abs() < 1e-6);
+        /// ```
+        #[inline(always)]
+        fn tan(self) -> f32 {
+            libm::tanf(self.to_radians().0)
+        }
+
+        /// Returns both the sine and the cosine of the angle `(sin, cos)`
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// let (sin, cos) = Radians(core::f32::consts::FRAC_PI_2).sin_cos();
+        /// 
+        /// assert!((sin - 1.0).abs() < 1e-6);
+        /// assert!(cos.abs() < 1e-6);
+        /// ```
+        #[inline(always)]
+        fn sin_cos(self) -> (f32, f32) {
+            libm::sincosf(self.to_radians().0)
+        }
+    }
 // 
 
 // #######################
@@ -321,6 +896,8 @@ use crate as syunit;
         /// // Duration conversion
         /// assert_eq!(Seconds(2.0), Duration::from_secs(2).into());
         /// assert_eq!(Seconds(0.005), Duration::from_millis(5).into());
+        /// assert_eq!(Duration::try_from(Seconds(0.5)), Ok(Duration::from_millis(500)));
+        /// assert!(Duration::try_from(Seconds(-0.5)).is_err());
         /// 
         /// // Basic maths
         /// assert_eq!(Seconds(3.0) + Seconds(2.0), Seconds(5.0));
@@ -330,26 +907,69 @@ use crate as syunit;
         /// 
         /// // Comparisions
         /// assert!(Seconds(1.0) > Seconds(-1.0));
+        /// 
+        /// // Displayed with the symbol "s"
+        /// assert_eq!(format!("{}", Seconds(2.0)), "2s");
         /// ```
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct Seconds(pub f32);
-        basic_unit!(Seconds);
+        basic_unit!(Seconds, "s", Dimension::TIME, 1.0);
         additive_unit!(Seconds);
-        
-        impl From<Seconds> for Duration {
-            #[inline(always)]
-            fn from(value : Seconds) -> Self {
-                Duration::from_secs_f32(value.0)
-            }
-        }
 
-        impl From<Duration> for Seconds {
-            #[inline(always)]
-            fn from(value : Duration) -> Self {
-                Self(value.as_secs_f32())
-            }
-        }
+        /// Represents a point in time in seconds since an epoch, the time equivalent of a position unit
+        /// 
+        /// Like [PositionMM](metric::PositionMM) and [Millimeters](metric::Millimeters), a [Timestamp] is an *absolute* time, while
+        /// [Seconds] are a *duration*. The difference of two timestamps is a duration, and durations can be added to timestamps, but
+        /// two timestamps cannot be added to each other.
+        /// 
+        /// As the value is stored as [f32], the resolution decreases with the distance to the epoch (about 0.25ms after one hour, 
+        /// 8ms after one day). The epoch should therefore be chosen close to the times being handled, e.g. the start of the program
+        /// or the boot of the device.
+        /// 
+        /// ```rust
+        /// use core::time::Duration;
+        /// 
+        /// use syunit::prelude::*;
+        /// 
+        /// let start = Timestamp::from_duration(Duration::from_millis(1500));
+        /// let end = Timestamp::from_ticks(4_000, Hertz(1000.0));
+        /// 
+        /// // Time math
+        /// assert_eq!(end - start, Seconds(2.5));
+        /// assert_eq!(start + Seconds(2.5), end);
+        /// assert_eq!(end.to_duration(), Ok(Duration::from_secs(4)));
+        /// ```
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
+        pub struct Timestamp(pub f32);
+        basic_unit!(Timestamp, "s", Dimension::TIME, 1.0);
+        position_unit!(Timestamp, Seconds);
+
+        /// Represents seconds squared (s^2), the square of [Seconds]
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// // t² = v / j
+        /// assert_eq!(MMPerSecond(8.0) / MMPerSecond3(2.0), Seconds2(4.0));
+        /// assert_eq!(Seconds2(4.0).sqrt(), Seconds(2.0));
+        /// ```
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
+        pub struct Seconds2(pub f32);
+        basic_unit!(Seconds2, "s^2", Dimension::new(0, 0, 2, 0), 1.0);
+        additive_unit!(Seconds2);
+        square_unit!(Seconds, Seconds2);
     //
 
     // Frequency
@@ -370,8 +990,11 @@ use crate as syunit;
         /// ```
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct Hertz(pub f32);
-        basic_unit!(Hertz, "Hz");
+        basic_unit!(Hertz, "Hz", Dimension::new(0, 0, -1, 0), 1.0);
         additive_unit!(Hertz);
 
         impl Mul<Hertz> for Seconds {
@@ -426,10 +1049,15 @@ use crate as syunit;
     /// ```
     #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+    #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+    #[repr(transparent)]
     pub struct PositionRad(pub f32);
-    syunit::basic_unit!(PositionRad, "rad");
+    syunit::basic_unit!(PositionRad, "rad", Dimension::ANGLE, 1.0);
     syunit::position_unit!(PositionRad, Radians);
 
+    impl AngleUnit for PositionRad { }
+
     /// Represents Radians (rad)
     /// 
     /// ```rust
@@ -446,11 +1074,69 @@ use crate as syunit;
     /// ```
     #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+    #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+    #[repr(transparent)]
     pub struct Radians(pub f32);
-    syunit::basic_unit!(Radians, "rad");
+    syunit::basic_unit!(Radians, "rad", Dimension::ANGLE, 1.0);
     syunit::additive_unit!(Radians);
     syunit::derive_units!(Radians, RadPerSecond, Seconds);
 
+    impl AngleUnit for Radians { }
+
+    impl Radians {
+        /// Returns the arcsine of `value` as an angle in the range `[-pi/2, pi/2]`, [NaN](Unit::NAN) if `value` is outside of `[-1, 1]`
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert!((Radians::asin(1.0) - Radians(core::f32::consts::FRAC_PI_2)).abs() < Radians(1e-6));
+        /// assert!(Radians::asin(2.0).is_nan());
+        /// ```
+        #[inline(always)]
+        pub fn asin(value : f32) -> Self {
+            Self(libm::asinf(value))
+        }
+
+        /// Returns the arccosine of `value` as an angle in the range `[0, pi]`, [NaN](Unit::NAN) if `value` is outside of `[-1, 1]`
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert!((Radians::acos(-1.0) - Radians(core::f32::consts::PI)).abs() < Radians(1e-6));
+        /// assert!(Radians::acos(-2.0).is_nan());
+        /// ```
+        #[inline(always)]
+        pub fn acos(value : f32) -> Self {
+            Self(libm::acosf(value))
+        }
+
+        /// Returns the arctangent of `value` as an angle in the range `[-pi/2, pi/2]`
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert!((Radians::atan(1.0) - Radians(core::f32::consts::FRAC_PI_4)).abs() < Radians(1e-6));
+        /// ```
+        #[inline(always)]
+        pub fn atan(value : f32) -> Self {
+            Self(libm::atanf(value))
+        }
+
+        /// Returns the four quadrant arctangent of `y` and `x` as an angle in the range `[-pi, pi]`
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// assert!((Radians::atan2(1.0, -1.0) - Radians(3.0 * core::f32::consts::FRAC_PI_4)).abs() < Radians(1e-6));
+        /// assert!((Radians::atan2(-1.0, -1.0) + Radians(3.0 * core::f32::consts::FRAC_PI_4)).abs() < Radians(1e-6));
+        /// ```
+        #[inline(always)]
+        pub fn atan2(y : f32, x : f32) -> Self {
+            Self(libm::atan2f(y, x))
+        }
+    }
+
     /// Represents Radians per second (rad/s)
     /// 
     /// ```rust
@@ -467,10 +1153,33 @@ use crate as syunit;
     /// ```
     #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+    #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+    #[repr(transparent)]
     pub struct RadPerSecond(pub f32);
-    syunit::basic_unit!(RadPerSecond, "rad/s");
+    syunit::basic_unit!(RadPerSecond, "rad/s", Dimension::new(0, 0, -1, 1), 1.0);
     syunit::additive_unit!(RadPerSecond);
     syunit::derive_units!(RadPerSecond, RadPerSecond2, Seconds);
+    syunit::square_unit!(RadPerSecond, Rad2PerSecond2);
+
+    /// Represents Radians squared per second squared (rad^2/s^2), the square of [RadPerSecond]
+    /// 
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// 
+    /// // ω² = 2 * α * φ
+    /// assert_eq!(RadPerSecond2(2.0) * Radians(4.0) * 2.0, Rad2PerSecond2(16.0));
+    /// assert_eq!(Rad2PerSecond2(16.0).sqrt(), RadPerSecond(4.0));
+    /// ```
+    #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+    #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+    #[repr(transparent)]
+    pub struct Rad2PerSecond2(pub f32);
+    syunit::basic_unit!(Rad2PerSecond2, "rad^2/s^2", Dimension::new(0, 0, -2, 2), 1.0);
+    syunit::additive_unit!(Rad2PerSecond2);
+    syunit::impl_full_conversion!(RadPerSecond2, Radians, Rad2PerSecond2);
 
     /// Represents Radians per second squared (rad/s^2)
     /// 
@@ -488,8 +1197,11 @@ use crate as syunit;
     /// ```
     #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+    #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+    #[repr(transparent)]
     pub struct RadPerSecond2(pub f32);
-    syunit::basic_unit!(RadPerSecond2, "rad/s^2");
+    syunit::basic_unit!(RadPerSecond2, "rad/s^2", Dimension::new(0, 0, -2, 1), 1.0);
     syunit::additive_unit!(RadPerSecond2);
     syunit::derive_units!(RadPerSecond2, RadPerSecond3, Seconds);
 
@@ -509,7 +1221,11 @@ use crate as syunit;
     /// ```
     #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+    #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+    #[repr(transparent)]
     pub struct RadPerSecond3(pub f32);
-    syunit::basic_unit!(RadPerSecond3, "rad/s^3");
+    syunit::basic_unit!(RadPerSecond3, "rad/s^3", Dimension::new(0, 0, -3, 1), 1.0);
     syunit::additive_unit!(RadPerSecond3);
+    syunit::impl_full_conversion!(RadPerSecond3, Seconds2, RadPerSecond);
 //
\ No newline at end of file
@@ -0,0 +1,124 @@
+//! The traits keep the distinction between positions and distances: Arrays of [PositionUnits](crate::PositionUnit) convert into
+//! points, arrays of [AdditiveUnits](crate::AdditiveUnit) into vectors. Units are never mixed with each other, so the point or vector
+//! has to be converted back into the same unit type it was created from.
+//!
+//! With the "nalgebra" feature, positions convert into `nalgebra::Point` and distances into `nalgebra::SVector`, the
+//! operations of nalgebra itself then preserve the distinction (`point - point = vector`).
+//!
+//! ```rust, ignore
+//! use syunit::prelude::*;
+//! use syunit::linalg::*;
+//!
+//! let start : nalgebra::Point3<f32> = [ PositionMM(1.0), PositionMM(2.0), PositionMM(3.0) ].to_point();
+//! let end : nalgebra::Point3<f32> = [ PositionMM(4.0), PositionMM(6.0), PositionMM(3.0) ].to_point();
+//!
+//! let travel : [Millimeters; 3] = FromVector::from_vector(end - start);
+//! assert_eq!(travel, [ Millimeters(3.0), Millimeters(4.0), Millimeters(0.0) ]);
+//! ```
+//!
+//! With the "glam" feature, both positions and distances convert into `glam::Vec2`, `glam::Vec3` and `glam::Vec4`, as glam has no separate
+//! point types. The distinction is kept by the unit types only.
+
+#[cfg(any(feature = "nalgebra", feature = "glam"))]
+use crate::{AdditiveUnit, PositionUnit};
+
+// ################
+// #    Traits    #
+// ################
+    /// Converts an array of positions into a point of a linear algebra library
+    pub trait ToPoint<T> {
+        /// Creates the point `T` out of the positions
+        fn to_point(self) -> T;
+    }
+
+    /// Creates an array of positions out of a point of a linear algebra library
+    pub trait FromPoint<T> : Sized {
+        /// Creates the positions out of the `point`
+        fn from_point(point : T) -> Self;
+    }
+
+    /// Converts an array of distances into a vector of a linear algebra library
+    pub trait ToVector<T> {
+        /// Creates the vector `T` out of the distances
+        fn to_vector(self) -> T;
+    }
+
+    /// Creates an array of distances out of a vector of a linear algebra library
+    pub trait FromVector<T> : Sized {
+        /// Creates the distances out of the `vector`
+        fn from_vector(vector : T) -> Self;
+    }
+//
+
+// ##################
+// #    nalgebra    #
+// ##################
+    #[cfg(feature = "nalgebra")]
+    impl<P : PositionUnit, const N : usize> ToPoint<nalgebra::Point<f32, N>> for [P; N] {
+        fn to_point(self) -> nalgebra::Point<f32, N> {
+            nalgebra::Point::from(self.map(Into::into))
+        }
+    }
+
+    #[cfg(feature = "nalgebra")]
+    impl<P : PositionUnit, const N : usize> FromPoint<nalgebra::Point<f32, N>> for [P; N] {
+        fn from_point(point : nalgebra::Point<f32, N>) -> Self {
+            core::array::from_fn(|i| P::from(point[i]))
+        }
+    }
+
+    #[cfg(feature = "nalgebra")]
+    impl<D : AdditiveUnit, const N : usize> ToVector<nalgebra::SVector<f32, N>> for [D; N] {
+        fn to_vector(self) -> nalgebra::SVector<f32, N> {
+            nalgebra::SVector::from(self.map(Into::into))
+        }
+    }
+
+    #[cfg(feature = "nalgebra")]
+    impl<D : AdditiveUnit, const N : usize> FromVector<nalgebra::SVector<f32, N>> for [D; N] {
+        fn from_vector(vector : nalgebra::SVector<f32, N>) -> Self {
+            core::array::from_fn(|i| D::from(vector[i]))
+        }
+    }
+//
+
+// ##############
+// #    glam    #
+// ##############
+    /// Implements the conversion traits between unit arrays of length `$n` and the glam vector `$vec`
+    #[cfg(feature = "glam")]
+    macro_rules! impl_glam {
+        ( $vec:ty, $n:literal ) => {
+            impl<P : PositionUnit> ToPoint<$vec> for [P; $n] {
+                fn to_point(self) -> $vec {
+                    <$vec>::from_array(self.map(Into::into))
+                }
+            }
+
+            impl<P : PositionUnit> FromPoint<$vec> for [P; $n] {
+                fn from_point(point : $vec) -> Self {
+                    point.to_array().map(P::from)
+                }
+            }
+
+            impl<D : AdditiveUnit> ToVector<$vec> for [D; $n] {
+                fn to_vector(self) -> $vec {
+                    <$vec>::from_array(self.map(Into::into))
+                }
+            }
+
+            impl<D : AdditiveUnit> FromVector<$vec> for [D; $n] {
+                fn from_vector(vector : $vec) -> Self {
+                    vector.to_array().map(D::from)
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "glam")]
+    impl_glam!(glam::Vec2, 2);
+    #[cfg(feature = "glam")]
+    impl_glam!(glam::Vec3, 3);
+    #[cfg(feature = "glam")]
+    impl_glam!(glam::Vec4, 4);
+//
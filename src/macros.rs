@@ -213,7 +213,10 @@
     /// Implements the basics for a unit
     #[macro_export]
     macro_rules! basic_unit_helper {
-        ( $a:ident ) => {      
+        ( $a:ident ) => {
+            syunit::basic_unit_helper!( $a, "", syunit::Dimension::NONE, 1.0 );
+        };
+        ( $a:ident, $sym:literal, $dim:expr, $scale:expr ) => {      
             // Display traits
                 impl core::str::FromStr for $a {
                     type Err = <f32 as core::str::FromStr>::Err;
@@ -225,7 +228,7 @@
 
                 impl core::fmt::Debug for $a {
                     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                        f.write_fmt(format_args!("{}({})", stringify!($a), self.0))
+                        f.debug_tuple(stringify!($a)).field(&self.0).finish()
                     }
                 }
 
@@ -236,10 +239,10 @@
                     }
                 }
 
-                impl core::convert::Into<f32> for $a {
+                impl core::convert::From<$a> for f32 {
                     #[inline(always)]
-                    fn into(self) -> f32 {
-                        self.0
+                    fn from(value : $a) -> Self {
+                        value.0
                     }
                 }
             //
@@ -295,6 +298,17 @@
                 }
             // 
 
+            // Direction
+                impl core::ops::Mul<syunit::Direction> for $a {
+                    type Output = $a;
+
+                    #[inline]
+                    fn mul(self, rhs : syunit::Direction) -> Self::Output {
+                        Self(self.0 * rhs.signum())
+                    }
+                }
+            // 
+
             // Factor
                 impl core::ops::Mul<syunit::Factor> for $a {
                     type Output = $a;
@@ -315,11 +329,292 @@
                 const NEG_INFINITY : Self = Self(f32::NEG_INFINITY);
                 /// NaN value of this unit (f32::NAN)
                 const NAN : Self = Self(f32::NAN);
+                /// Largest finite value of this unit (f32::MAX)
+                const MAX : Self = Self(f32::MAX);
+                /// Smallest finite value of this unit (f32::MIN)
+                const MIN : Self = Self(f32::MIN);
+
+                const SYMBOL : &'static str = $sym;
+                const NAME : &'static str = stringify!($a);
+                const DIMENSION : syunit::Dimension = $dim;
+                const SCALE_TO_SI : f64 = $scale;
             }
+
+            // Embedded logging
+            syunit::__defmt_unit!( $a, $sym );
+            syunit::__ufmt_unit!( $a, $sym );
+
+            // Numeric traits
+            syunit::__num_traits_unit!( $a );
+
+            // Testing
+            syunit::__arbitrary_unit!( $a );
+            syunit::__proptest_unit!( $a );
+
+            // Schemas
+            syunit::__schemars_unit!( $a );
         };
     }
 
+    // Implementations depending on the features of syunit itself, the helper macros are defined either as implementation or as
+    // an empty macro, so the features of the crate calling the macros do not matter
+        /// Implements [defmt::Format] for the given unit
+        #[cfg(feature = "defmt")]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __defmt_unit {
+            ( $a:ident, $sym:literal ) => {
+                impl syunit::__private::defmt::Format for $a {
+                    fn format(&self, f : syunit::__private::defmt::Formatter<'_>) {
+                        syunit::format::defmt_unit(f, self.0, $sym)
+                    }
+                }
+            };
+        }
+
+        #[cfg(not(feature = "defmt"))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __defmt_unit {
+            ( $a:ident, $sym:literal ) => { };
+        }
+
+        /// Implements [ufmt::uDisplay] for the given unit
+        #[cfg(feature = "ufmt")]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __ufmt_unit {
+            ( $a:ident, $sym:literal ) => {
+                impl syunit::__private::ufmt::uDisplay for $a {
+                    fn fmt<W>(&self, f : &mut syunit::__private::ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+                    where
+                        W : syunit::__private::ufmt::uWrite + ?Sized
+                    {
+                        syunit::format::ufmt_unit(f, self.0, $sym)
+                    }
+                }
+            };
+        }
+
+        #[cfg(not(feature = "ufmt"))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __ufmt_unit {
+            ( $a:ident, $sym:literal ) => { };
+        }
+
+        /// Implements [num_traits::Bounded], [num_traits::FromPrimitive] and [num_traits::ToPrimitive] for the given unit
+        #[cfg(feature = "num-traits")]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __num_traits_unit {
+            ( $a:ident ) => {
+                impl syunit::__private::num_traits::Bounded for $a {
+                    #[inline]
+                    fn min_value() -> Self {
+                        <Self as syunit::Unit>::MIN
+                    }
+
+                    #[inline]
+                    fn max_value() -> Self {
+                        <Self as syunit::Unit>::MAX
+                    }
+                }
+
+                impl syunit::__private::num_traits::FromPrimitive for $a {
+                    #[inline]
+                    fn from_i64(n : i64) -> Option<Self> {
+                        Some(Self(n as f32))
+                    }
+
+                    #[inline]
+                    fn from_u64(n : u64) -> Option<Self> {
+                        Some(Self(n as f32))
+                    }
+
+                    #[inline]
+                    fn from_f32(n : f32) -> Option<Self> {
+                        Some(Self(n))
+                    }
+
+                    #[inline]
+                    fn from_f64(n : f64) -> Option<Self> {
+                        Some(Self(n as f32))
+                    }
+                }
+
+                impl syunit::__private::num_traits::ToPrimitive for $a {
+                    #[inline]
+                    fn to_i64(&self) -> Option<i64> {
+                        syunit::__private::num_traits::ToPrimitive::to_i64(&self.0)
+                    }
+
+                    #[inline]
+                    fn to_u64(&self) -> Option<u64> {
+                        syunit::__private::num_traits::ToPrimitive::to_u64(&self.0)
+                    }
+
+                    #[inline]
+                    fn to_f32(&self) -> Option<f32> {
+                        Some(self.0)
+                    }
+
+                    #[inline]
+                    fn to_f64(&self) -> Option<f64> {
+                        Some(self.0 as f64)
+                    }
+                }
+            };
+        }
+
+        #[cfg(not(feature = "num-traits"))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __num_traits_unit {
+            ( $a:ident ) => { };
+        }
+
+        /// Implements [num_traits::Zero] for the given additive unit
+        #[cfg(feature = "num-traits")]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __num_traits_additive {
+            ( $a:ident ) => {
+                impl syunit::__private::num_traits::Zero for $a {
+                    #[inline]
+                    fn zero() -> Self {
+                        <Self as syunit::Unit>::ZERO
+                    }
+
+                    #[inline]
+                    fn is_zero(&self) -> bool {
+                        self.0 == 0.0
+                    }
+                }
+            };
+        }
+
+        #[cfg(not(feature = "num-traits"))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __num_traits_additive {
+            ( $a:ident ) => { };
+        }
+
+        /// Implements [arbitrary::Arbitrary] for the given unit, generating any [f32] value
+        #[cfg(feature = "arbitrary")]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __arbitrary_unit {
+            ( $a:ident ) => {
+                impl<'a> syunit::__private::arbitrary::Arbitrary<'a> for $a {
+                    fn arbitrary(u : &mut syunit::__private::arbitrary::Unstructured<'a>) -> syunit::__private::arbitrary::Result<Self> {
+                        Ok(Self(<f32 as syunit::__private::arbitrary::Arbitrary<'a>>::arbitrary(u)?))
+                    }
+
+                    fn size_hint(depth : usize) -> (usize, Option<usize>) {
+                        <f32 as syunit::__private::arbitrary::Arbitrary<'a>>::size_hint(depth)
+                    }
+                }
+            };
+        }
+
+        #[cfg(not(feature = "arbitrary"))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __arbitrary_unit {
+            ( $a:ident ) => { };
+        }
+
+        /// Implements [proptest::arbitrary::Arbitrary] for the given unit, generating any [f32] value
+        #[cfg(feature = "proptest")]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __proptest_unit {
+            ( $a:ident ) => {
+                impl syunit::__private::proptest::arbitrary::Arbitrary for $a {
+                    type Parameters = ();
+                    type Strategy = syunit::__private::proptest::strategy::Map<syunit::__private::proptest::num::f32::Any, fn(f32) -> Self>;
+
+                    fn arbitrary_with(_ : Self::Parameters) -> Self::Strategy {
+                        syunit::__private::proptest::strategy::Strategy::prop_map(
+                            syunit::__private::proptest::num::f32::ANY, $a as fn(f32) -> Self
+                        )
+                    }
+                }
+            };
+        }
+
+        #[cfg(not(feature = "proptest"))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __proptest_unit {
+            ( $a:ident ) => { };
+        }
+
+        /// Implements [schemars::JsonSchema] for the given unit, see [schema::number](crate::schema::number)
+        #[cfg(feature = "schemars")]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __schemars_unit {
+            ( $a:ident ) => {
+                impl syunit::__private::schemars::JsonSchema for $a {
+                    fn inline_schema() -> bool {
+                        true
+                    }
+
+                    fn schema_name() -> syunit::__private::Cow<'static, str> {
+                        syunit::__private::Cow::Borrowed(stringify!($a))
+                    }
+
+                    fn schema_id() -> syunit::__private::Cow<'static, str> {
+                        syunit::__private::Cow::Borrowed(concat!(module_path!(), "::", stringify!($a)))
+                    }
+
+                    fn json_schema(_ : &mut syunit::__private::schemars::SchemaGenerator) -> syunit::__private::schemars::Schema {
+                        syunit::schema::number::<Self>()
+                    }
+                }
+            };
+        }
+
+        #[cfg(not(feature = "schemars"))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __schemars_unit {
+            ( $a:ident ) => { };
+        }
+    //
+
     /// Implements the basics for a unit
+    /// 
+    /// ### Syntax
+    /// 
+    /// ```rust, ignore
+    /// ( $name:ident )
+    /// ( $name:ident, $sym:literal )
+    /// ( $name:ident, $sym:literal, $dim:expr, $scale:expr )
+    /// ```
+    /// 
+    /// - `sym`: The symbol of the unit, used for displaying it, see [Unit::SYMBOL](crate::Unit::SYMBOL)
+    /// - `dim`: The [Dimension](crate::Dimension) of the unit, see [Unit::DIMENSION](crate::Unit::DIMENSION)
+    /// - `scale`: The scale of the unit as [f64], one unit equals `scale` SI units, see [Unit::SCALE_TO_SI](crate::Unit::SCALE_TO_SI)
+    /// 
+    /// The conversions with [DynQuantity](crate::DynQuantity) are only implemented if the dimension and scale are given. Units defined
+    /// without them keep the metadata [Dimension::NONE](crate::Dimension::NONE) with a scale of `1.0`, which does not describe the
+    /// unit, so new units with a symbol should always provide the dimension and scale
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+    /// pub struct Steps(pub f32);
+    /// syunit::basic_unit!(Steps, "steps");
+    ///
+    /// assert_eq!(format!("{}", Steps(200.0)), "200steps");
+    /// assert_eq!(Steps::DIMENSION, Dimension::NONE);
+    /// assert_eq!(Steps::SCALE_TO_SI, 1.0);
+    /// ```
     #[macro_export]
     macro_rules! basic_unit {
         ( $name:ident ) => {
@@ -327,19 +622,29 @@
 
             impl core::fmt::Display for $name {
                 fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    <f32 as core::fmt::Display>::fmt(&self.0, f)
+                    syunit::format::write_unit(f, self.0, "")
                 }
             }
         };
         ( $name:ident, $sym:literal ) => {
-            syunit::basic_unit_helper!( $name );
+            syunit::basic_unit_helper!( $name, $sym, syunit::Dimension::NONE, 1.0 );
 
             impl core::fmt::Display for $name {
                 fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    f.write_fmt(format_args!("{}{}", self.0, $sym))
+                    syunit::format::write_unit(f, self.0, $sym)
                 }
             }
-        }
+        };
+        ( $name:ident, $sym:literal, $dim:expr, $scale:expr ) => {
+            syunit::basic_unit_helper!( $name, $sym, $dim, $scale );
+            syunit::dyn_unit!( $name );
+
+            impl core::fmt::Display for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    syunit::format::write_unit(f, self.0, $sym)
+                }
+            }
+        };
     }
 //
 
@@ -379,7 +684,22 @@ macro_rules! additive_unit {
             }
         }
 
+        impl core::iter::Sum<$unit> for $unit {
+            fn sum<I : Iterator<Item = $unit>>(iter : I) -> Self {
+                $unit(iter.map(|unit| unit.0).sum())
+            }
+        }
+
+        impl<'a> core::iter::Sum<&'a $unit> for $unit {
+            fn sum<I : Iterator<Item = &'a $unit>>(iter : I) -> Self {
+                $unit(iter.map(|unit| unit.0).sum())
+            }
+        }
+
         impl syunit::AdditiveUnit for $unit { }
+
+        // Numeric traits
+        syunit::__num_traits_additive!( $unit );
     };
 }
 
@@ -423,6 +743,10 @@ macro_rules! position_unit {
             }
         }
 
+        impl syunit::PositionUnit for $pos {
+            type Distance = $unit;
+        }
+
         syunit::impl_conversion!($pos, $unit);
     };
 }
@@ -467,4 +791,305 @@ macro_rules! inertia_unit {
             }
         }
     };
+}
+
+/// Implements [SquareUnit](crate::SquareUnit) and [RootUnit](crate::RootUnit) for the given units
+/// 
+/// ```rust, ignore
+/// ( $unit:ident, $squared:ident )
+/// ```
+/// 
+/// resulting in:
+/// 
+/// ```rust, ignore
+/// unit * unit = squared
+/// squared / unit = unit
+/// ```
+#[macro_export]
+macro_rules! square_unit {
+    ( $unit:ident, $squared:ident ) => {
+        syunit::impl_mul!( $unit, $unit, $squared );
+        syunit::impl_div!( $squared, $unit, $unit );
+
+        impl syunit::SquareUnit for $unit {
+            type Squared = $squared;
+        }
+
+        impl syunit::RootUnit for $squared {
+            type Root = $unit;
+        }
+    };
+}
+
+/// Implements [CubeUnit](crate::CubeUnit) for the given units, requires [square_unit!] to be called for `$unit` and `$squared` first
+/// 
+/// ```rust, ignore
+/// ( $unit:ident, $squared:ident, $cubed:ident )
+/// ```
+/// 
+/// resulting in:
+/// 
+/// ```rust, ignore
+/// unit * squared = cubed
+/// squared * unit = cubed
+/// cubed / unit = squared
+/// cubed / squared = unit
+/// ```
+#[macro_export]
+macro_rules! cube_unit {
+    ( $unit:ident, $squared:ident, $cubed:ident ) => {
+        syunit::impl_mul_bidir!( $unit, $squared, $cubed );
+        syunit::impl_div_bidir!( $cubed, $unit, $squared );
+
+        impl syunit::CubeUnit for $unit {
+            type Cubed = $cubed;
+        }
+    };
+}
+/// Defines a complete [UnitSet](crate::UnitSet) in one block, scaled relative to an already existing reference set
+/// 
+/// ### Syntax
+/// 
+/// ```rust, ignore
+/// unit_system! {
+///     (attributes)
+///     (visibility) struct SetName : ReferenceSet {
+///         time : TimeUnit;
+///         (attributes) position : Name("symbol") = scale * ReferencePosition;
+///         (attributes) distance : Name("symbol") = scale * ReferenceDistance;
+///         (attributes) velocity : Name("symbol") = scale * ReferenceVelocity;
+///         (attributes) acceleration : Name("symbol") = scale * ReferenceAcceleration;
+///         (attributes) jolt : Name("symbol") = scale * ReferenceJolt;
+///         (attributes) force : Name("symbol") = scale * ReferenceForce;
+///         (attributes) inertia : Name("symbol") = scale * ReferenceInertia;
+///     }
+/// }
+/// ```
+/// 
+/// - `time`: The time unit, shared with the reference set
+/// - `scale`: A [f32] literal, one of the new units equals `scale` reference units
+/// 
+/// Every unit is created as a tuple struct and receives conversions from and into its reference unit. The derivative and
+/// integral chains (distance, velocity, acceleration, jolt) as well as the force and inertia relations are calculated with the
+/// reference units, so any combination of scales stays correct. The compiler checks that the reference units match the ones of
+/// the reference set. No squared units are generated, as the [kinematics](crate::kinematics) functions only require the
+/// [UnitSet](crate::UnitSet) and work with every generated set.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use syunit::prelude::*;
+/// 
+/// syunit::unit_system! {
+///     /// A [UnitSet] centered around micrometers
+///     pub struct MetricUM : MetricMM {
+///         time : Seconds;
+///         /// Position in micrometers (um)
+///         position : PositionUM("um") = 0.001 * PositionMM;
+///         /// Micrometers (um)
+///         distance : Micrometers("um") = 0.001 * Millimeters;
+///         /// Micrometers per second (um/s)
+///         velocity : UMPerSecond("um/s") = 0.001 * MMPerSecond;
+///         /// Micrometers per second squared (um/s^2)
+///         acceleration : UMPerSecond2("um/s^2") = 0.001 * MMPerSecond2;
+///         /// Micrometers per second cubed (um/s^3)
+///         jolt : UMPerSecond3("um/s^3") = 0.001 * MMPerSecond3;
+///         /// Millinewtons (mN)
+///         force : MilliNewtons("mN") = 0.001 * Newtons;
+///         /// Grams (g)
+///         inertia : Grams("g") = 0.001 * Kilogramms;
+///     }
+/// }
+/// 
+/// fn get_distance<U : UnitSet>(vel : U::Velocity, time : U::Time) -> U::Distance {
+///     vel * time
+/// }
+/// 
+/// assert_eq!(get_distance::<MetricUM>(UMPerSecond(4.0), Seconds(2.0)), Micrometers(8.0));
+/// assert_eq!(PositionUM(5.0) - PositionUM(3.0), Micrometers(2.0));
+/// assert_eq!(Millimeters::from(Micrometers(2000.0)), Millimeters(2.0));
+/// assert_eq!(Grams(1000.0) * UMPerSecond2(1000.0), MilliNewtons(1.0));
+/// 
+/// // Kinematics
+/// assert_eq!(syunit::kinematics::vel_after_dist::<MetricUM>(UMPerSecond(0.0), UMPerSecond2(2.0), Micrometers(4.0)), UMPerSecond(4.0));
+/// assert_eq!(
+///     syunit::kinematics::time_for_velocity_jolt::<MetricUM>(UMPerSecond(0.0), UMPerSecond(1.0), UMPerSecond2(2.0), UMPerSecond3(1.0)),
+///     Seconds(2.0)
+/// );
+/// ```
+#[macro_export]
+macro_rules! unit_system {
+    (
+        $(#[$set_meta:meta])*
+        $vis:vis struct $set:ident : $ref_set:ident {
+            time : $time:ident;
+            $(#[$pos_meta:meta])* position : $pos:ident ( $pos_sym:literal ) = $pos_scale:literal * $pos_ref:ident;
+            $(#[$dist_meta:meta])* distance : $dist:ident ( $dist_sym:literal ) = $dist_scale:literal * $dist_ref:ident;
+            $(#[$vel_meta:meta])* velocity : $vel:ident ( $vel_sym:literal ) = $vel_scale:literal * $vel_ref:ident;
+            $(#[$acc_meta:meta])* acceleration : $acc:ident ( $acc_sym:literal ) = $acc_scale:literal * $acc_ref:ident;
+            $(#[$jolt_meta:meta])* jolt : $jolt:ident ( $jolt_sym:literal ) = $jolt_scale:literal * $jolt_ref:ident;
+            $(#[$force_meta:meta])* force : $force:ident ( $force_sym:literal ) = $force_scale:literal * $force_ref:ident;
+            $(#[$inertia_meta:meta])* inertia : $inertia:ident ( $inertia_sym:literal ) = $inertia_scale:literal * $inertia_ref:ident;
+        }
+    ) => {
+        // Units
+        syunit::unit_system!(@unit $(#[$pos_meta])* $vis $pos, $pos_sym, $pos_scale, $pos_ref);
+        syunit::unit_system!(@unit $(#[$dist_meta])* $vis $dist, $dist_sym, $dist_scale, $dist_ref);
+        syunit::unit_system!(@unit $(#[$vel_meta])* $vis $vel, $vel_sym, $vel_scale, $vel_ref);
+        syunit::unit_system!(@unit $(#[$acc_meta])* $vis $acc, $acc_sym, $acc_scale, $acc_ref);
+        syunit::unit_system!(@unit $(#[$jolt_meta])* $vis $jolt, $jolt_sym, $jolt_scale, $jolt_ref);
+        syunit::unit_system!(@unit $(#[$force_meta])* $vis $force, $force_sym, $force_scale, $force_ref);
+        syunit::unit_system!(@unit $(#[$inertia_meta])* $vis $inertia, $inertia_sym, $inertia_scale, $inertia_ref);
+
+        syunit::position_unit!($pos, $dist);
+        syunit::additive_unit!($dist);
+        syunit::additive_unit!($vel);
+        syunit::additive_unit!($acc);
+        syunit::additive_unit!($jolt);
+        syunit::additive_unit!($force);
+        syunit::additive_unit!($inertia);
+
+        // Kinematics
+        syunit::unit_system!(@derive $dist, $vel, $time, $dist_ref, $vel_ref);
+        syunit::unit_system!(@derive $vel, $acc, $time, $vel_ref, $acc_ref);
+        syunit::unit_system!(@derive $acc, $jolt, $time, $acc_ref, $jolt_ref);
+
+        // Dynamics
+        syunit::unit_system!(@mul $inertia, $acc, $force, $inertia_ref, $acc_ref);
+        syunit::unit_system!(@mul $acc, $inertia, $force, $acc_ref, $inertia_ref);
+        syunit::unit_system!(@div $force, $inertia, $acc, $force_ref, $inertia_ref);
+        syunit::unit_system!(@div $force, $acc, $inertia, $force_ref, $acc_ref);
+
+        // Set
+        $(#[$set_meta])*
+        #[derive(Clone, Copy, Debug, Default)]
+        $vis struct $set { }
+
+        impl syunit::UnitSet for $set {
+            type Time = $time;
+
+            type Position = $pos;
+
+            type Distance = $dist;
+            type Velocity = $vel;
+            type Acceleration = $acc;
+            type Jolt = $jolt;
+
+            type Force = $force;
+            type Inertia = $inertia;
+        }
+
+        // Makes sure the reference units belong to the reference set
+        const _ : fn() = {
+            fn check_reference<U : syunit::UnitSet<
+                Time = $time, Position = $pos_ref, 
+                Distance = $dist_ref, Velocity = $vel_ref, Acceleration = $acc_ref, Jolt = $jolt_ref,
+                Force = $force_ref, Inertia = $inertia_ref
+            >>() { }
+
+            check_reference::<$ref_set>
+        };
+    };
+    (@unit $(#[$meta:meta])* $vis:vis $name:ident, $sym:literal, $scale:literal, $ref:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[repr(transparent)]
+        $vis struct $name(pub f32);
+        syunit::basic_unit!($name, $sym, <$ref as syunit::Unit>::DIMENSION, $scale * <$ref as syunit::Unit>::SCALE_TO_SI);
+        syunit::impl_conversion!($name, $ref, $scale);
+    };
+    (@mul $input:ident, $by:ident, $output:ident, $input_ref:ident, $by_ref:ident) => {
+        impl core::ops::Mul<$by> for $input {
+            type Output = $output;
+
+            #[inline]
+            fn mul(self, rhs : $by) -> $output {
+                $output::from(<$input_ref>::from(self) * <$by_ref>::from(rhs))
+            }
+        }
+    };
+    (@div $input:ident, $by:ident, $output:ident, $input_ref:ident, $by_ref:ident) => {
+        impl core::ops::Div<$by> for $input {
+            type Output = $output;
+
+            #[inline]
+            fn div(self, rhs : $by) -> $output {
+                $output::from(<$input_ref>::from(self) / <$by_ref>::from(rhs))
+            }
+        }
+    };
+    (@derive $dist:ident, $vel:ident, $time:ident, $dist_ref:ident, $vel_ref:ident) => {
+        impl core::ops::Div<$time> for $dist {
+            type Output = $vel;
+
+            #[inline]
+            fn div(self, rhs : $time) -> $vel {
+                $vel::from(<$dist_ref>::from(self) / rhs)
+            }
+        }
+
+        impl core::ops::Mul<$time> for $vel {
+            type Output = $dist;
+
+            #[inline]
+            fn mul(self, rhs : $time) -> $dist {
+                $dist::from(<$vel_ref>::from(self) * rhs)
+            }
+        }
+
+        impl core::ops::Mul<$vel> for $time {
+            type Output = $dist;
+
+            #[inline]
+            fn mul(self, rhs : $vel) -> $dist {
+                $dist::from(self * <$vel_ref>::from(rhs))
+            }
+        }
+
+        syunit::unit_system!(@div $dist, $vel, $time, $dist_ref, $vel_ref);
+
+        impl syunit::DerivableUnit<$time> for $dist { 
+            type Result = $vel;
+        }
+
+        impl syunit::IntegrableUnit<$time> for $vel { 
+            type Result = $dist;
+        }
+    };
+}
+
+
+/// Implements the conversions between the given unit and [DynQuantity](crate::DynQuantity), using the 
+/// [dimension](crate::Unit::DIMENSION) and [scale](crate::Unit::SCALE_TO_SI) of the unit
+/// 
+/// Units implementing [PositionUnit](crate::PositionUnit) are converted into positions and only accept positions
+/// 
+/// Automatically called by [basic_unit!] if a dimension is given
+#[macro_export]
+macro_rules! dyn_unit {
+    ( $unit:ident ) => {
+        impl From<$unit> for syunit::DynQuantity {
+            #[inline]
+            fn from(value : $unit) -> Self {
+                #[allow(unused_imports)]
+                use syunit::__private::NotPosition as _;
+
+                syunit::DynQuantity::new(value.0, <$unit as syunit::Unit>::DIMENSION, <$unit as syunit::Unit>::SCALE_TO_SI)
+                    .with_position(<syunit::__private::IsPosition<$unit>>::VALUE)
+            }
+        }
+
+        impl TryFrom<syunit::DynQuantity> for $unit {
+            type Error = syunit::DimensionError;
+
+            fn try_from(value : syunit::DynQuantity) -> Result<Self, Self::Error> {
+                #[allow(unused_imports)]
+                use syunit::__private::NotPosition as _;
+
+                value.check_dimension(<$unit as syunit::Unit>::DIMENSION)?;
+                value.check_position(<syunit::__private::IsPosition<$unit>>::VALUE)?;
+                Ok(Self(value.value_in(<$unit as syunit::Unit>::SCALE_TO_SI)))
+            }
+        }
+    };
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize}; 
 
-use crate::{Seconds, UnitSet, PositionRad, Radians, RadPerSecond, RadPerSecond2, RadPerSecond3};
+use crate::{Dimension, Seconds, Seconds2, UnitSet, PositionRad, Radians, RadPerSecond, RadPerSecond2, RadPerSecond3};
 
 use crate as syunit;
 
@@ -12,34 +12,88 @@ use crate as syunit;
         /// Represents a position in metric millimeters (mm)
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct PositionMM(pub f32);
-        syunit::basic_unit!(PositionMM, "mm");
+        syunit::basic_unit!(PositionMM, "mm", Dimension::LENGTH, 0.001);
         syunit::position_unit!(PositionMM, Millimeters);
         syunit::impl_full_conversion!(PositionRad, Millimeters, PositionMM);
 
         /// Represents metric millimeters (mm)
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct Millimeters(pub f32);
-        syunit::basic_unit!(Millimeters, "mm");
+        syunit::basic_unit!(Millimeters, "mm", Dimension::LENGTH, 0.001);
         syunit::additive_unit!(Millimeters);
         syunit::derive_units!(Millimeters, MMPerSecond, Seconds);
         syunit::impl_mul_bidir!(Radians, Millimeters, Millimeters);
+        syunit::square_unit!(Millimeters, Millimeters2);
+        syunit::cube_unit!(Millimeters, Millimeters2, Millimeters3);
+
+        /// Represents an area in metric millimeters squared (mm^2)
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
+        pub struct Millimeters2(pub f32);
+        syunit::basic_unit!(Millimeters2, "mm^2", Dimension::new(2, 0, 0, 0), 0.000_001);
+        syunit::additive_unit!(Millimeters2);
+
+        /// Represents a volume in metric millimeters cubed (mm^3)
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
+        pub struct Millimeters3(pub f32);
+        syunit::basic_unit!(Millimeters3, "mm^3", Dimension::new(3, 0, 0, 0), 0.000_000_001);
+        syunit::additive_unit!(Millimeters3);
 
         /// Represents metric millimeters per second (mm/s)
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct MMPerSecond(pub f32);
-        syunit::basic_unit!(MMPerSecond, "mm/s");
+        syunit::basic_unit!(MMPerSecond, "mm/s", Dimension::new(1, 0, -1, 0), 0.001);
         syunit::additive_unit!(MMPerSecond);
         syunit::derive_units!(MMPerSecond, MMPerSecond2, Seconds);
         syunit::impl_full_conversion!(RadPerSecond, Millimeters, MMPerSecond);
+        syunit::square_unit!(MMPerSecond, MM2PerSecond2);
+
+        /// Represents metric millimeters per second squared (mm^2/s^2), the square of [MMPerSecond]
+        /// 
+        /// ```rust
+        /// use syunit::prelude::*;
+        /// 
+        /// // v² = 2 * a * s
+        /// assert_eq!(MMPerSecond2(2.0) * Millimeters(4.0) * 2.0, MM2PerSecond2(16.0));
+        /// assert_eq!(MM2PerSecond2(16.0).sqrt(), MMPerSecond(4.0));
+        /// ```
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
+        pub struct MM2PerSecond2(pub f32);
+        syunit::basic_unit!(MM2PerSecond2, "mm^2/s^2", Dimension::new(2, 0, -2, 0), 0.000_001);
+        syunit::additive_unit!(MM2PerSecond2);
+        syunit::impl_full_conversion!(MMPerSecond2, Millimeters, MM2PerSecond2);
 
         /// Represents metric millimeters per second squared (mm/s^2)
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct MMPerSecond2(pub f32);
-        syunit::basic_unit!(MMPerSecond2, "mm/s^2");
+        syunit::basic_unit!(MMPerSecond2, "mm/s^2", Dimension::new(1, 0, -2, 0), 0.001);
         syunit::additive_unit!(MMPerSecond2);
         syunit::derive_units!(MMPerSecond2, MMPerSecond3, Seconds);
         syunit::impl_full_conversion!(RadPerSecond2, Millimeters, MMPerSecond2);
@@ -47,24 +101,34 @@ use crate as syunit;
         /// Represents metric meters
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct MMPerSecond3(pub f32);
-        syunit::basic_unit!(MMPerSecond3, "mm/s^3");
+        syunit::basic_unit!(MMPerSecond3, "mm/s^3", Dimension::new(1, 0, -3, 0), 0.001);
         syunit::additive_unit!(MMPerSecond3);
         syunit::impl_full_conversion!(RadPerSecond3, Millimeters, MMPerSecond3);
+        syunit::impl_full_conversion!(MMPerSecond3, Seconds2, MMPerSecond);
 
         /// Represents Newtons
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct Newtons(pub f32);
-        syunit::basic_unit!(Newtons, "N");
+        syunit::basic_unit!(Newtons, "N", Dimension::new(1, 1, -2, 0), 1.0);
         syunit::additive_unit!(Newtons);
         syunit::impl_full_conversion!(MMPerSecond2, Kilogramms, Newtons, 0.001); 
 
         /// Represents metric Kilogramms
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct Kilogramms(pub f32);
-        syunit::basic_unit!(Kilogramms, "kg");
+        syunit::basic_unit!(Kilogramms, "kg", Dimension::MASS, 1.0);
         syunit::additive_unit!(Kilogramms);
         syunit::inertia_unit!(Kilogramms, f32, Kilogramms);
         syunit::inertia_unit!(Kilogramms, Millimeters, KgMeter2, 0.000_001);
@@ -96,8 +160,11 @@ use crate as syunit;
         /// Represents metric Newtonmeters
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct NewtonMeters(pub f32);
-        syunit::basic_unit!(NewtonMeters, "Nm");
+        syunit::basic_unit!(NewtonMeters, "Nm", Dimension::new(2, 1, -2, 0), 1.0);
         syunit::additive_unit!(NewtonMeters);
         syunit::impl_full_conversion!(KgMeter2, RadPerSecond2, NewtonMeters);   
         syunit::impl_full_conversion!(Newtons, Millimeters, NewtonMeters, 0.001); 
@@ -105,8 +172,11 @@ use crate as syunit;
         /// Represents a second moment of inertia in Kilogramms times meters squared
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct KgMeter2(pub f32);
-        syunit::basic_unit!(KgMeter2, "kgm^2");
+        syunit::basic_unit!(KgMeter2, "kgm^2", Dimension::new(2, 1, 0, 0), 1.0);
         syunit::additive_unit!(KgMeter2);
         syunit::inertia_unit!(KgMeter2, f32, KgMeter2);
         syunit::inertia_unit!(KgMeter2, Millimeters, Kilogramms, 1_000_000.0);
@@ -139,8 +209,11 @@ use crate as syunit;
         /// Represents a position in metric 
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct PositionM(pub f32);
-        syunit::basic_unit!(PositionM, "m");
+        syunit::basic_unit!(PositionM, "m", Dimension::LENGTH, 1.0);
         syunit::position_unit!(PositionM, Meters);
         syunit::impl_full_conversion!(PositionRad, Meters, PositionM);
         syunit::impl_conversion!(Meters, Millimeters, 1000.0);
@@ -148,11 +221,38 @@ use crate as syunit;
         /// Represents metric meters (m)
         #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
         pub struct Meters(pub f32);
-        syunit::basic_unit!(Meters, "m");
+        syunit::basic_unit!(Meters, "m", Dimension::LENGTH, 1.0);
         syunit::additive_unit!(Meters);
         // syunit::derive_units!(Meters, MMPerSecond, Seconds);
         syunit::impl_mul_bidir!(Radians, Meters, Meters);
+        syunit::square_unit!(Meters, Meters2);
+        syunit::cube_unit!(Meters, Meters2, Meters3);
+
+        /// Represents an area in metric meters squared (m^2)
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
+        pub struct Meters2(pub f32);
+        syunit::basic_unit!(Meters2, "m^2", Dimension::new(2, 0, 0, 0), 1.0);
+        syunit::additive_unit!(Meters2);
+        syunit::impl_conversion!(Meters2, Millimeters2, 1_000_000.0);
+
+        /// Represents a volume in metric meters cubed (m^3)
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+        #[repr(transparent)]
+        pub struct Meters3(pub f32);
+        syunit::basic_unit!(Meters3, "m^3", Dimension::new(3, 0, 0, 0), 1.0);
+        syunit::additive_unit!(Meters3);
+        syunit::impl_conversion!(Meters3, Millimeters3, 1_000_000_000.0);
     //
 
     // TODO: Finish metric units set
@@ -0,0 +1,96 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::Unit;
+
+// #################
+// #    OrdUnit    #
+// #################
+    /// A wrapper around a unit that implements [Eq], [Ord] and [Hash], so it can be sorted and used as a key in maps
+    ///
+    /// The value is canonicalized when creating the wrapper: `-0.0` becomes `0.0` and every `NaN` becomes [f32::NAN].
+    /// Ordering is done using [f32::total_cmp], placing `NaN` after positive infinity
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    ///
+    /// let mut frequencies = [ OrdUnit::new(Hertz(50.0)), OrdUnit::new(Hertz::NAN), OrdUnit::new(Hertz(10.0)) ];
+    /// frequencies.sort();
+    ///
+    /// assert_eq!(frequencies[0].get(), Hertz(10.0));
+    /// assert_eq!(frequencies[1].get(), Hertz(50.0));
+    /// assert!(frequencies[2].get().is_nan());
+    ///
+    /// // Canonicalization
+    /// assert_eq!(OrdUnit::new(Millimeters(-0.0)), OrdUnit::new(Millimeters(0.0)));
+    /// assert_eq!(OrdUnit::new(Millimeters::NAN), OrdUnit::new(-Millimeters::NAN));
+    ///
+    /// // Usage as a key
+    /// let mut gains = std::collections::BTreeMap::new();
+    /// gains.insert(OrdUnit::new(Hertz(100.0)), 0.5);
+    /// gains.insert(OrdUnit::new(Hertz(10.0)), 1.0);
+    ///
+    /// assert_eq!(gains.first_key_value(), Some((&OrdUnit::new(Hertz(10.0)), &1.0)));
+    /// ```
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct OrdUnit<U : Unit>(U);
+
+    impl<U : Unit> OrdUnit<U> {
+        /// Creates a new `OrdUnit`, canonicalizing the given value
+        #[inline]
+        pub fn new(unit : U) -> Self {
+            if unit.is_nan() {
+                Self(U::NAN)
+            } else if unit == U::ZERO {
+                Self(U::ZERO)
+            } else {
+                Self(unit)
+            }
+        }
+
+        /// Returns the inner unit
+        #[inline]
+        pub fn get(self) -> U {
+            self.0
+        }
+    }
+
+    impl<U : Unit> From<U> for OrdUnit<U> {
+        #[inline]
+        fn from(unit : U) -> Self {
+            Self::new(unit)
+        }
+    }
+
+    impl<U : Unit> PartialEq for OrdUnit<U> {
+        fn eq(&self, other : &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl<U : Unit> Eq for OrdUnit<U> { }
+
+    impl<U : Unit> PartialOrd for OrdUnit<U> {
+        fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<U : Unit> Ord for OrdUnit<U> {
+        fn cmp(&self, other : &Self) -> Ordering {
+            self.0.total_cmp(&other.0)
+        }
+    }
+
+    impl<U : Unit> Hash for OrdUnit<U> {
+        fn hash<H : Hasher>(&self, state : &mut H) {
+            <U as Into<f32>>::into(self.0).to_bits().hash(state)
+        }
+    }
+
+    impl<U : Unit> core::fmt::Display for OrdUnit<U> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Display::fmt(&self.0, f)
+        }
+    }
+//
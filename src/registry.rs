@@ -0,0 +1,129 @@
+use crate::{Dimension, Unit};
+use crate::{Hertz, PositionRad, Radians, RadPerSecond, Rad2PerSecond2, RadPerSecond2, RadPerSecond3, Seconds, Seconds2, Timestamp};
+use crate::imperial::Inches;
+use crate::metric::*;
+
+// ##################
+// #    UnitInfo    #
+// ##################
+    /// Metadata of a unit, gathered from the associated constants of the [Unit] trait
+    ///
+    /// Allows working with units whose type is not known at compile time, e.g. for building unit pickers in UIs
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    ///
+    /// let info = UnitInfo::of::<MMPerSecond>();
+    ///
+    /// assert_eq!(info.name, "MMPerSecond");
+    /// assert_eq!(info.symbol, "mm/s");
+    /// assert_eq!(info.to_si(1500.0), 1.5);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct UnitInfo {
+        /// Name of the unit type, see [Unit::NAME]
+        pub name : &'static str,
+        /// Symbol of the unit, see [Unit::SYMBOL]
+        pub symbol : &'static str,
+        /// Dimension of the unit, see [Unit::DIMENSION]
+        pub dimension : Dimension,
+        /// Scale of the unit, see [Unit::SCALE_TO_SI]
+        pub scale_to_si : f64
+    }
+
+    impl UnitInfo {
+        /// Gathers the metadata of the unit `U`
+        pub const fn of<U : Unit>() -> Self {
+            Self {
+                name: U::NAME,
+                symbol: U::SYMBOL,
+                dimension: U::DIMENSION,
+                scale_to_si: U::SCALE_TO_SI
+            }
+        }
+
+        /// Converts a value given in this unit into SI base units
+        #[inline]
+        pub fn to_si(&self, value : f32) -> f32 {
+            (value as f64 * self.scale_to_si) as f32
+        }
+
+        /// Converts a value given in SI base units into this unit
+        #[inline]
+        pub fn from_si(&self, value : f32) -> f32 {
+            (value as f64 / self.scale_to_si) as f32
+        }
+
+        /// Searches the [UNITS] registry for a unit with the given symbol
+        ///
+        /// Distances are listed before their positions, so a shared symbol like `"mm"` resolves to the distance unit
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// assert_eq!(UnitInfo::by_symbol("mm"), Some(&UnitInfo::of::<Millimeters>()));
+        /// assert_eq!(UnitInfo::by_symbol("furlong"), None);
+        /// ```
+        pub fn by_symbol(symbol : &str) -> Option<&'static UnitInfo> {
+            UNITS.iter().find(|info| info.symbol == symbol)
+        }
+
+        /// Searches the [UNITS] registry for a unit with the given name
+        pub fn by_name(name : &str) -> Option<&'static UnitInfo> {
+            UNITS.iter().find(|info| info.name == name)
+        }
+
+        /// Iterates over all units in the [UNITS] registry sharing the given dimension
+        ///
+        /// ```rust
+        /// use syunit::prelude::*;
+        ///
+        /// let lengths : Vec<&str> = UnitInfo::with_dimension(Dimension::LENGTH).map(|info| info.symbol).collect();
+        /// assert!(lengths.contains(&"mm"));
+        /// assert!(lengths.contains(&"in"));
+        /// ```
+        pub fn with_dimension(dimension : Dimension) -> impl Iterator<Item = &'static UnitInfo> {
+            UNITS.iter().filter(move |info| info.dimension == dimension)
+        }
+    }
+//
+
+// ##################
+// #    Registry    #
+// ##################
+    /// All units defined by this crate
+    pub static UNITS : &[UnitInfo] = &[
+        // Time
+        UnitInfo::of::<Seconds>(),
+        UnitInfo::of::<Timestamp>(),
+        UnitInfo::of::<Seconds2>(),
+        UnitInfo::of::<Hertz>(),
+        // Angles
+        UnitInfo::of::<Radians>(),
+        UnitInfo::of::<PositionRad>(),
+        UnitInfo::of::<RadPerSecond>(),
+        UnitInfo::of::<RadPerSecond2>(),
+        UnitInfo::of::<RadPerSecond3>(),
+        UnitInfo::of::<Rad2PerSecond2>(),
+        // Metric lengths
+        UnitInfo::of::<Millimeters>(),
+        UnitInfo::of::<PositionMM>(),
+        UnitInfo::of::<MMPerSecond>(),
+        UnitInfo::of::<MMPerSecond2>(),
+        UnitInfo::of::<MMPerSecond3>(),
+        UnitInfo::of::<Millimeters2>(),
+        UnitInfo::of::<Millimeters3>(),
+        UnitInfo::of::<MM2PerSecond2>(),
+        UnitInfo::of::<Meters>(),
+        UnitInfo::of::<PositionM>(),
+        UnitInfo::of::<Meters2>(),
+        UnitInfo::of::<Meters3>(),
+        // Imperial lengths
+        UnitInfo::of::<Inches>(),
+        // Dynamics
+        UnitInfo::of::<Newtons>(),
+        UnitInfo::of::<Kilogramms>(),
+        UnitInfo::of::<NewtonMeters>(),
+        UnitInfo::of::<KgMeter2>()
+    ];
+//
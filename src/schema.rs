@@ -0,0 +1,112 @@
+//! Units are serialized as plain numbers, their schema is a `number` with the name of the unit as title and its symbol in the
+//! description and the `x-unit` keyword, so configuration editors can display the unit next to the value.
+//!
+//! ```rust
+//! use syunit::prelude::*;
+//!
+//! let schema = schemars::schema_for!(Millimeters);
+//!
+//! assert_eq!(schema.get("type").unwrap(), "number");
+//! assert_eq!(schema.get("x-unit").unwrap(), "mm");
+//! ```
+//!
+//! Fields serialized as a string with symbol using [serde_symbol](crate::serde_symbol) get their schema from [with_symbol]:
+//!
+//! ```rust, ignore
+//! use syunit::prelude::*;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+//! struct Config {
+//!     #[serde(with = "syunit::serde_symbol")]
+//!     #[schemars(schema_with = "syunit::schema::with_symbol::<Millimeters>")]
+//!     travel : Millimeters
+//! }
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use schemars::{json_schema, Schema, SchemaGenerator};
+
+use crate::{Dimension, Unit, UnitInfo};
+
+/// Regular expression matching a decimal number with optional sign and exponent
+const NUMBER_PATTERN : &str = r"[-+]?(\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?";
+
+/// Adds the title, description and `x-unit` keyword of the unit `U` to the `schema`
+fn describe<U : Unit>(mut schema : Schema) -> Schema {
+    schema.insert("title".into(), U::NAME.into());
+
+    if !U::SYMBOL.is_empty() {
+        schema.insert("description".into(), format!("Value in {}", U::SYMBOL).into());
+        schema.insert("x-unit".into(), U::SYMBOL.into());
+    }
+
+    schema
+}
+
+/// Escapes all characters of the symbol that have a special meaning in regular expressions
+fn escape_symbol(symbol : &str) -> String {
+    let mut escaped = String::with_capacity(symbol.len());
+
+    for c in symbol.chars() {
+        if "\\^$.|?*+()[]{}/".contains(c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// The schema of the unit `U` in its number form, used by the [JsonSchema](schemars::JsonSchema) implementation of all units
+pub fn number<U : Unit>() -> Schema {
+    describe::<U>(json_schema!({ "type": "number" }))
+}
+
+/// The schema of the unit `U` serialized with [serde_symbol](crate::serde_symbol), meant to be used with
+/// `#[schemars(schema_with = "syunit::schema::with_symbol::<U>")]`
+///
+/// Accepts plain numbers and strings of a number followed by the symbol of `U` or any other symbol of the same dimension in the
+/// [UNITS](crate::UNITS) registry, e.g. `"12.5mm"` or `"0.5 m"` for [Millimeters](crate::metric::Millimeters)
+///
+/// ```rust
+/// use syunit::prelude::*;
+///
+/// let schema = syunit::schema::with_symbol::<Millimeters>(&mut schemars::SchemaGenerator::default());
+/// let pattern = schema.get("anyOf").unwrap()[1]["pattern"].as_str().unwrap();
+///
+/// assert!(pattern.contains("mm|"));
+/// assert!(pattern.contains("|in"));
+/// ```
+pub fn with_symbol<U : Unit>(_generator : &mut SchemaGenerator) -> Schema {
+    let mut symbols : Vec<&str> = Vec::new();
+
+    if !U::SYMBOL.is_empty() {
+        symbols.push(U::SYMBOL);
+    }
+
+    if U::DIMENSION != Dimension::NONE {
+        for info in UnitInfo::with_dimension(U::DIMENSION) {
+            if !symbols.contains(&info.symbol) {
+                symbols.push(info.symbol);
+            }
+        }
+    }
+
+    let symbols : Vec<String> = symbols.into_iter().map(escape_symbol).collect();
+    let pattern = if symbols.is_empty() {
+        format!(r"^\s*{}\s*$", NUMBER_PATTERN)
+    } else {
+        format!(r"^\s*{}\s*({})?\s*$", NUMBER_PATTERN, symbols.join("|"))
+    };
+
+    describe::<U>(json_schema!({
+        "anyOf": [
+            { "type": "number" },
+            { "type": "string", "pattern": pattern }
+        ]
+    }))
+}
@@ -0,0 +1,88 @@
+//! Use with `#[serde(with = "syunit::serde_symbol")]` on fields of units that provide a [Dimension](crate::Dimension). The unit is
+//! serialized as its [Display] form, e.g. `"12.5mm"`, and deserialized out of either a plain number in the unit of the field or a
+//! string with any symbol of the exact same dimension, including the angle, in the [UNITS](crate::UNITS) registry, which gets
+//! converted into the unit of the field.
+//!
+//! ```rust
+//! use syunit::prelude::*;
+//!
+//! #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "syunit::serde_symbol")]
+//!     travel : Millimeters,
+//!     #[serde(with = "syunit::serde_symbol")]
+//!     home : PositionMM
+//! }
+//!
+//! let config = Config { travel: Millimeters(12.5), home: PositionMM(-2.0) };
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"travel":"12.5mm","home":"-2mm"}"#);
+//!
+//! assert_eq!(serde_json::from_str::<Config>(r#"{"travel":"12.5mm","home":"-2mm"}"#).unwrap(), config);
+//! assert_eq!(serde_json::from_str::<Config>(r#"{"travel":"0.5 m","home":"-0.002m"}"#).unwrap().travel, Millimeters(500.0));
+//! assert_eq!(serde_json::from_str::<Config>(r#"{"travel":12.5,"home":-2}"#).unwrap(), config);
+//!
+//! // Symbols of other dimensions are rejected
+//! assert!(serde_json::from_str::<Config>(r#"{"travel":"2s","home":0}"#).is_err());
+//! ```
+
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+
+use serde::{de, Deserializer, Serializer};
+
+use crate::{DimensionError, DynQuantity, Unit};
+
+/// Serializes the `unit` as string of its value followed by its symbol
+pub fn serialize<U : Unit, S : Serializer>(unit : &U, serializer : S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(unit)
+}
+
+/// Deserializes a unit out of a plain number or a string of a number followed by a symbol of the same dimension
+pub fn deserialize<'de, U, D>(deserializer : D) -> Result<U, D::Error>
+where
+    U : Unit + TryFrom<DynQuantity, Error = DimensionError>,
+    DynQuantity : From<U>,
+    D : Deserializer<'de>
+{
+    deserializer.deserialize_any(SymbolVisitor(PhantomData))
+}
+
+struct SymbolVisitor<U>(PhantomData<U>);
+
+impl<U> de::Visitor<'_> for SymbolVisitor<U>
+where
+    U : Unit + TryFrom<DynQuantity, Error = DimensionError>,
+    DynQuantity : From<U>
+{
+    type Value = U;
+
+    fn expecting(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "a number or a string of a number followed by the symbol `{}`", U::SYMBOL)
+    }
+
+    fn visit_f64<E : de::Error>(self, v : f64) -> Result<Self::Value, E> {
+        Ok(U::from(v as f32))
+    }
+
+    fn visit_i64<E : de::Error>(self, v : i64) -> Result<Self::Value, E> {
+        Ok(U::from(v as f32))
+    }
+
+    fn visit_u64<E : de::Error>(self, v : u64) -> Result<Self::Value, E> {
+        Ok(U::from(v as f32))
+    }
+
+    fn visit_str<E : de::Error>(self, v : &str) -> Result<Self::Value, E> {
+        // Plain numbers are given in the unit of the field
+        if let Ok(value) = v.trim().parse::<f32>() {
+            return Ok(U::from(value));
+        }
+
+        // The full dimension has to match, including the angle, so e.g. `"2 Hz"` is no valid `RadPerSecond`
+        let quantity : DynQuantity = v.parse().map_err(E::custom)?;
+        quantity.check_dimension(U::DIMENSION).map_err(E::custom)?;
+
+        // The symbol does not tell positions and distances apart, so the quantity takes the kind of the field
+        U::try_from(quantity.with_position(DynQuantity::from(U::ZERO).position)).map_err(E::custom)
+    }
+}
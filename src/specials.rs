@@ -10,8 +10,20 @@ use serde::{Serialize, Deserialize};
     /// 
     /// - Unitless
     /// 
+    /// ```rust
+    /// use syunit::Factor;
+    /// 
+    /// // The product of factors is a factor again
+    /// assert_eq!([ Factor::HALF, Factor::HALF ].iter().product::<Factor>(), Factor::new(0.25));
+    /// ```
+    ///
+    /// Like the units, the factor has the layout of an [f32]. Casting it out of bytes with the "bytemuck" or "zerocopy" features does
+    /// not check the bounds, just like [Factor::new_unchecked]
     #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+    #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+    #[repr(transparent)]
     pub struct Factor(f32);
 
     impl Factor {
@@ -38,7 +50,7 @@ use serde::{Serialize, Deserialize};
 
         /// Tries to create a new factor, will be `None` if `val` is not between or equal to 0 and 1
         pub fn try_new(val : f32) -> Option<Self> {
-            if (val >= 0.0) & (val <= 1.0) {
+            if (0.0..=1.0).contains(&val) {
                 Some(Self(val))
             } else {
                 None
@@ -47,7 +59,7 @@ use serde::{Serialize, Deserialize};
 
         /// Creates a new factor without checking bounds
         /// 
-        /// # Unsafe
+        /// # Safety
         /// 
         /// An out of bounds factor might throw up important logic
         /// 
@@ -86,23 +98,191 @@ use serde::{Serialize, Deserialize};
         }
     }
 
+    impl core::iter::Product<Factor> for Factor {
+        fn product<I : Iterator<Item = Factor>>(iter : I) -> Self {
+            Self(iter.map(|factor| factor.0).product())
+        }
+    }
+
+    impl<'a> core::iter::Product<&'a Factor> for Factor {
+        fn product<I : Iterator<Item = &'a Factor>>(iter : I) -> Self {
+            Self(iter.map(|factor| factor.0).product())
+        }
+    }
+
     impl core::fmt::Display for Factor {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            self.0.fmt(f)
+            core::fmt::Display::fmt(&self.0, f)
         }
     }
 
     impl core::fmt::Debug for Factor {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            f.write_fmt(format_args!("Factor({})", self.0))
+            f.debug_tuple("Factor").field(&self.0).finish()
+        }
+    }
+
+    #[cfg(feature = "defmt")]
+    impl defmt::Format for Factor {
+        fn format(&self, f : defmt::Formatter<'_>) {
+            defmt::write!(f, "{=f32}", self.0)
+        }
+    }
+
+    #[cfg(feature = "num-traits")]
+    impl num_traits::One for Factor {
+        #[inline]
+        fn one() -> Self {
+            Self::MAX
+        }
+    }
+
+    #[cfg(feature = "num-traits")]
+    impl num_traits::Bounded for Factor {
+        #[inline]
+        fn min_value() -> Self {
+            Self::MIN
+        }
+
+        #[inline]
+        fn max_value() -> Self {
+            Self::MAX
+        }
+    }
+
+    #[cfg(feature = "num-traits")]
+    impl num_traits::FromPrimitive for Factor {
+        /// Only `0` and `1` are valid factors
+        #[inline]
+        fn from_i64(n : i64) -> Option<Self> {
+            Self::try_new(n as f32)
+        }
+
+        /// Only `0` and `1` are valid factors
+        #[inline]
+        fn from_u64(n : u64) -> Option<Self> {
+            Self::try_new(n as f32)
+        }
+
+        #[inline]
+        fn from_f32(n : f32) -> Option<Self> {
+            Self::try_new(n)
+        }
+
+        #[inline]
+        fn from_f64(n : f64) -> Option<Self> {
+            Self::try_new(n as f32)
+        }
+    }
+
+    #[cfg(feature = "num-traits")]
+    impl num_traits::ToPrimitive for Factor {
+        #[inline]
+        fn to_i64(&self) -> Option<i64> {
+            num_traits::ToPrimitive::to_i64(&self.0)
+        }
+
+        #[inline]
+        fn to_u64(&self) -> Option<u64> {
+            num_traits::ToPrimitive::to_u64(&self.0)
+        }
+
+        #[inline]
+        fn to_f32(&self) -> Option<f32> {
+            Some(self.0)
+        }
+
+        #[inline]
+        fn to_f64(&self) -> Option<f64> {
+            Some(self.0 as f64)
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    impl<'a> arbitrary::Arbitrary<'a> for Factor {
+        /// Generates factors in the range `0.0 ..= 1.0`
+        fn arbitrary(u : &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            Ok(Self(u16::arbitrary(u)? as f32 / u16::MAX as f32))
+        }
+
+        fn size_hint(depth : usize) -> (usize, Option<usize>) {
+            <u16 as arbitrary::Arbitrary<'a>>::size_hint(depth)
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl proptest::arbitrary::Arbitrary for Factor {
+        type Parameters = ();
+        type Strategy = proptest::strategy::Map<core::ops::RangeInclusive<f32>, fn(f32) -> Self>;
+
+        /// Generates factors in the range `0.0 ..= 1.0`
+        fn arbitrary_with(_ : Self::Parameters) -> Self::Strategy {
+            proptest::strategy::Strategy::prop_map(0.0 ..= 1.0, Factor::new as fn(f32) -> Self)
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    impl schemars::JsonSchema for Factor {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> alloc::borrow::Cow<'static, str> {
+            "Factor".into()
+        }
+
+        fn schema_id() -> alloc::borrow::Cow<'static, str> {
+            "syunit::Factor".into()
+        }
+
+        /// A number in the range `0.0 ..= 1.0`
+        fn json_schema(_ : &mut schemars::SchemaGenerator) -> schemars::Schema {
+            schemars::json_schema!({
+                "type": "number",
+                "title": "Factor",
+                "description": "Factor between 0 and 1",
+                "minimum": 0.0,
+                "maximum": 1.0
+            })
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    impl ufmt::uDisplay for Factor {
+        fn fmt<W : ufmt::uWrite + ?Sized>(&self, f : &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+            crate::format::ufmt_f32(f, self.0)
         }
     }
 //
 
 
 /// Direction of movement
+/// 
+/// `CW` is treated as the positive and `CCW` as the negative direction, which is also how units are signed when being 
+/// multiplied with a direction
+/// 
+/// ```rust
+/// use syunit::prelude::*;
+/// 
+/// // Inverting
+/// assert_eq!(Direction::CW.invert(), Direction::CCW);
+/// assert_eq!(!Direction::CCW, Direction::CW);
+/// 
+/// // Signs
+/// assert_eq!(Direction::from_sign(2.0), Some(Direction::CW));
+/// assert_eq!(Direction::from_sign(-2.0), Some(Direction::CCW));
+/// assert_eq!(Direction::from_sign(0.0), None);
+/// assert_eq!(Direction::CCW.signum(), -1.0);
+/// assert_eq!(Direction::CCW.signum_i8(), -1);
+/// 
+/// // Applying a direction to units
+/// assert_eq!(Millimeters(2.0) * Direction::CCW, Millimeters(-2.0));
+/// assert_eq!(Direction::CCW * Direction::CCW, Direction::CW);
+/// assert_eq!(Millimeters(-2.0).get_direction(), Direction::CCW);
+/// ```
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Direction {
     /// Counterclockwise (`false` / `0`)
@@ -132,6 +312,32 @@ impl Direction {
         }
     }
 
+    /// Like [Direction::from_bool], but respects the given [Polarity] of the logic signal
+    /// 
+    /// ```rust
+    /// use syunit::{Direction, Polarity};
+    /// 
+    /// assert_eq!(Direction::from_bool_with(true, Polarity::Normal), Direction::CW);
+    /// assert_eq!(Direction::from_bool_with(true, Polarity::Inverted), Direction::CCW);
+    /// ```
+    #[inline]
+    pub fn from_bool_with(b : bool, polarity : Polarity) -> Self {
+        polarity.apply(Self::from_bool(b))
+    }
+
+    /// Like [Direction::as_bool], but respects the given [Polarity] of the logic signal
+    /// 
+    /// ```rust
+    /// use syunit::{Direction, Polarity};
+    /// 
+    /// assert_eq!(Direction::CW.as_bool_with(Polarity::Normal), true);
+    /// assert_eq!(Direction::CW.as_bool_with(Polarity::Inverted), false);
+    /// ```
+    #[inline]
+    pub fn as_bool_with(self, polarity : Polarity) -> bool {
+        polarity.apply(self).as_bool()
+    }
+
     /// Parses a new `Direction` value from a `u8`
     /// - `0` is `CCW`
     /// - Everything else is `CW` 
@@ -148,12 +354,58 @@ impl Direction {
             Direction::CW => 1
         }
     }
+
+    /// Creates a new `Direction` from the sign of the given value
+    /// - Positive values are `CW`
+    /// - Negative values are `CCW`
+    /// - Zero (`0.0` and `-0.0`) and `NaN` have no direction and return `None`
+    #[inline]
+    pub fn from_sign(value : f32) -> Option<Self> {
+        if value > 0.0 {
+            Some(Direction::CW)
+        } else if value < 0.0 {
+            Some(Direction::CCW)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the sign of the direction as [f32]
+    /// - `CW` is `1.0`
+    /// - `CCW` is `-1.0`
+    #[inline]
+    pub fn signum(self) -> f32 {
+        match self {
+            Direction::CCW => -1.0,
+            Direction::CW => 1.0
+        }
+    }
+
+    /// Returns the sign of the direction as [i8]
+    /// - `CW` is `1`
+    /// - `CCW` is `-1`
+    #[inline]
+    pub fn signum_i8(self) -> i8 {
+        match self {
+            Direction::CCW => -1,
+            Direction::CW => 1
+        }
+    }
+
+    /// Returns the opposite direction
+    #[inline]
+    pub fn invert(self) -> Self {
+        match self {
+            Direction::CCW => Direction::CW,
+            Direction::CW => Direction::CCW
+        }
+    }
 }
 
 // Conversions
-impl Into<bool> for Direction {
-    fn into(self) -> bool {
-        self.as_bool()
+impl From<Direction> for bool {
+    fn from(value : Direction) -> Self {
+        value.as_bool()
     }
 }
 
@@ -163,9 +415,9 @@ impl From<bool> for Direction {
     }
 }
 
-impl Into<u8> for Direction {
-    fn into(self) -> u8 {
-        self.as_u8()
+impl From<Direction> for u8 {
+    fn from(value : Direction) -> Self {
+        value.as_u8()
     }
 }
 
@@ -173,4 +425,142 @@ impl From<u8> for Direction {
     fn from(value: u8) -> Self {
         Self::from_u8(value)
     }
-}
\ No newline at end of file
+}
+
+// Operations
+impl core::ops::Not for Direction {
+    type Output = Direction;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.invert()
+    }
+}
+
+impl core::ops::Mul<Direction> for Direction {
+    type Output = Direction;
+
+    #[inline]
+    fn mul(self, rhs : Direction) -> Self::Output {
+        if self == rhs { Direction::CW } else { Direction::CCW }
+    }
+}
+
+impl core::ops::Mul<Direction> for f32 {
+    type Output = f32;
+
+    #[inline]
+    fn mul(self, rhs : Direction) -> Self::Output {
+        self * rhs.signum()
+    }
+}
+
+// Testing
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Direction {
+    fn arbitrary(u : &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_bool(bool::arbitrary(u)?))
+    }
+
+    fn size_hint(depth : usize) -> (usize, Option<usize>) {
+        <bool as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Direction {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<proptest::bool::Any, fn(bool) -> Self>;
+
+    fn arbitrary_with(_ : Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(proptest::bool::ANY, Direction::from_bool as fn(bool) -> Self)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Direction {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "Direction".into()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        "syunit::Direction".into()
+    }
+
+    fn json_schema(_ : &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "title": "Direction",
+            "description": "Direction of movement, clockwise (CW) or counterclockwise (CCW)",
+            "enum": [ "CCW", "CW" ]
+        })
+    }
+}
+
+// Embedded logging
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Direction {
+    fn fmt<W : ufmt::uWrite + ?Sized>(&self, f : &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Self::CW => f.write_str("CW"),
+            Self::CCW => f.write_str("CCW")
+        }
+    }
+}
+
+/// Polarity of a direction signal, useful for pins that are wired inverted
+/// 
+/// ```rust
+/// use syunit::{Direction, Polarity};
+/// 
+/// assert_eq!(Polarity::Normal.apply(Direction::CW), Direction::CW);
+/// assert_eq!(Polarity::Inverted.apply(Direction::CW), Direction::CCW);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Polarity {
+    /// The signal maps directly onto the direction (`true` is `CW`)
+    #[default]
+    Normal,
+    /// The signal is inverted (`true` is `CCW`)
+    Inverted
+}
+
+impl Polarity {
+    /// Applies the polarity to the given direction, inverting it if the polarity is [Polarity::Inverted]
+    #[inline]
+    pub fn apply(self, dir : Direction) -> Direction {
+        match self {
+            Polarity::Normal => dir,
+            Polarity::Inverted => dir.invert()
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Polarity {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "Polarity".into()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        "syunit::Polarity".into()
+    }
+
+    fn json_schema(_ : &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "title": "Polarity",
+            "description": "Polarity of a direction signal",
+            "enum": [ "Normal", "Inverted" ]
+        })
+    }
+}
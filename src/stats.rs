@@ -0,0 +1,138 @@
+use crate::{Factor, Unit};
+
+/// Arithmetic mean of all units in `iter`, `None` if `iter` is empty
+///
+/// ```rust
+/// use syunit::prelude::*;
+/// use syunit::stats;
+///
+/// assert_eq!(stats::mean([ Millimeters(1.0), Millimeters(2.0), Millimeters(6.0) ]), Some(Millimeters(3.0)));
+/// assert_eq!(stats::mean([ PositionMM(1.0), PositionMM(3.0) ]), Some(PositionMM(2.0)));
+/// assert_eq!(stats::mean::<Millimeters, _>([]), None);
+/// ```
+pub fn mean<U : Unit, I : IntoIterator<Item = U>>(iter : I) -> Option<U> {
+    let mut sum = 0.0;
+    let mut count = 0;
+
+    for unit in iter {
+        sum += unit.into();
+        count += 1;
+    }
+
+    if count == 0 {
+        None
+    } else {
+        Some(U::from(sum / count as f32))
+    }
+}
+
+/// Smallest unit in `iter`, `NaN` values are ignored, `None` if `iter` is empty
+///
+/// ```rust
+/// use syunit::prelude::*;
+/// use syunit::stats;
+///
+/// assert_eq!(stats::min([ Hertz(5.0), Hertz::NAN, Hertz(-2.0) ]), Some(Hertz(-2.0)));
+/// ```
+pub fn min<U : Unit, I : IntoIterator<Item = U>>(iter : I) -> Option<U> {
+    iter.into_iter().reduce(U::min)
+}
+
+/// Biggest unit in `iter`, `NaN` values are ignored, `None` if `iter` is empty
+///
+/// ```rust
+/// use syunit::prelude::*;
+/// use syunit::stats;
+///
+/// assert_eq!(stats::max([ Hertz(5.0), Hertz::NAN, Hertz(-2.0) ]), Some(Hertz(5.0)));
+/// ```
+pub fn max<U : Unit, I : IntoIterator<Item = U>>(iter : I) -> Option<U> {
+    iter.into_iter().reduce(U::max)
+}
+
+/// Root mean square of all units in `iter`, `None` if `iter` is empty
+///
+/// ```rust
+/// use syunit::prelude::*;
+/// use syunit::stats;
+///
+/// assert_eq!(stats::rms([ Newtons(3.0), Newtons(-3.0) ]), Some(Newtons(3.0)));
+/// assert_eq!(stats::rms([ Newtons(1.0), Newtons(7.0) ]), Some(Newtons(5.0)));
+/// ```
+pub fn rms<U : Unit, I : IntoIterator<Item = U>>(iter : I) -> Option<U> {
+    let mut sum = 0.0;
+    let mut count = 0;
+
+    for unit in iter {
+        let value : f32 = unit.into();
+        sum += value * value;
+        count += 1;
+    }
+
+    if count == 0 {
+        None
+    } else {
+        Some(U::from(libm::sqrtf(sum / count as f32)))
+    }
+}
+
+/// Population standard deviation of all units in `iter`, `None` if `iter` is empty
+///
+/// Uses Welford's algorithm to stay numerically stable
+///
+/// ```rust
+/// use syunit::prelude::*;
+/// use syunit::stats;
+///
+/// let samples = [ 2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0 ].map(MMPerSecond);
+///
+/// assert_eq!(stats::std_dev(samples), Some(MMPerSecond(2.0)));
+/// assert_eq!(stats::std_dev([ MMPerSecond(3.0) ]), Some(MMPerSecond(0.0)));
+/// ```
+pub fn std_dev<U : Unit, I : IntoIterator<Item = U>>(iter : I) -> Option<U> {
+    let mut mean = 0.0;
+    let mut sq_diff_sum = 0.0;
+    let mut count = 0;
+
+    for unit in iter {
+        let value : f32 = unit.into();
+        count += 1;
+
+        let delta = value - mean;
+        mean += delta / count as f32;
+        sq_diff_sum += delta * (value - mean);
+    }
+
+    if count == 0 {
+        None
+    } else {
+        Some(U::from(libm::sqrtf(sq_diff_sum / count as f32)))
+    }
+}
+
+/// Mean of all units in `iter`, each one weighted by the given [Factor]
+///
+/// `None` if `iter` is empty or all weights are zero
+///
+/// ```rust
+/// use syunit::prelude::*;
+/// use syunit::stats;
+///
+/// assert_eq!(stats::weighted_mean([ (Millimeters(2.0), Factor::MAX), (Millimeters(8.0), Factor::HALF) ]), Some(Millimeters(4.0)));
+/// assert_eq!(stats::weighted_mean([ (Millimeters(2.0), Factor::MIN) ]), None);
+/// ```
+pub fn weighted_mean<U : Unit, I : IntoIterator<Item = (U, Factor)>>(iter : I) -> Option<U> {
+    let mut sum = 0.0;
+    let mut weight_sum = 0.0;
+
+    for (unit, weight) in iter {
+        sum += unit.into() * weight.as_f32();
+        weight_sum += weight.as_f32();
+    }
+
+    if weight_sum == 0.0 {
+        None
+    } else {
+        Some(U::from(sum / weight_sum))
+    }
+}
@@ -0,0 +1,56 @@
+use uom::si::f32 as quantities;
+use uom::si::{acceleration, angle, angular_acceleration, angular_jerk, angular_velocity, area, force, frequency, jerk, length,
+    mass, moment_of_inertia, time, torque, velocity, volume};
+
+use crate::{Hertz, Radians, RadPerSecond, RadPerSecond2, RadPerSecond3, Seconds};
+use crate::imperial::Inches;
+use crate::metric::*;
+
+/// Implements the conversions between a unit and its `uom` quantity, scaled by the given `uom` unit
+macro_rules! impl_uom {
+    ( $unit:ident, $quantity:ident, $uom_unit:path ) => {
+        impl From<$unit> for quantities::$quantity {
+            #[inline]
+            fn from(value : $unit) -> Self {
+                Self::new::<$uom_unit>(value.0)
+            }
+        }
+
+        impl From<quantities::$quantity> for $unit {
+            #[inline]
+            fn from(quantity : quantities::$quantity) -> Self {
+                Self(quantity.get::<$uom_unit>())
+            }
+        }
+    };
+}
+
+// Time
+impl_uom!(Seconds, Time, time::second);
+impl_uom!(Hertz, Frequency, frequency::hertz);
+
+// Angles
+impl_uom!(Radians, Angle, angle::radian);
+impl_uom!(RadPerSecond, AngularVelocity, angular_velocity::radian_per_second);
+impl_uom!(RadPerSecond2, AngularAcceleration, angular_acceleration::radian_per_second_squared);
+impl_uom!(RadPerSecond3, AngularJerk, angular_jerk::radian_per_second_cubed);
+
+// Metric lengths
+impl_uom!(Millimeters, Length, length::millimeter);
+impl_uom!(Millimeters2, Area, area::square_millimeter);
+impl_uom!(Millimeters3, Volume, volume::cubic_millimeter);
+impl_uom!(MMPerSecond, Velocity, velocity::millimeter_per_second);
+impl_uom!(MMPerSecond2, Acceleration, acceleration::millimeter_per_second_squared);
+impl_uom!(MMPerSecond3, Jerk, jerk::millimeter_per_second_cubed);
+impl_uom!(Meters, Length, length::meter);
+impl_uom!(Meters2, Area, area::square_meter);
+impl_uom!(Meters3, Volume, volume::cubic_meter);
+
+// Imperial lengths
+impl_uom!(Inches, Length, length::inch);
+
+// Dynamics
+impl_uom!(Newtons, Force, force::newton);
+impl_uom!(Kilogramms, Mass, mass::kilogram);
+impl_uom!(NewtonMeters, Torque, torque::newton_meter);
+impl_uom!(KgMeter2, MomentOfInertia, moment_of_inertia::kilogram_square_meter);
@@ -0,0 +1,276 @@
+#![crate_name = "syunit_derive"]
+//! Derive macros for the [syunit](https://docs.rs/syunit) crate
+//!
+//! Usually this crate is not used directly, but through the `derive` feature of `syunit`, which re-exports [macro@Unit]
+// Rules
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, Ident, LitStr, Type};
+
+// ####################
+// #    Attributes    #
+// ####################
+    /// A `derive(time = ..., to = ...)` entry of the `unit` attribute
+    struct DeriveEntry {
+        time : Ident,
+        to : Ident
+    }
+
+    /// All the information given by the `unit` attributes of a struct
+    #[derive(Default)]
+    struct UnitAttributes {
+        symbol : Option<LitStr>,
+        dimension : Option<Expr>,
+        scale : Option<Expr>,
+        additive : bool,
+        position_of : Option<Ident>,
+        derives : Vec<DeriveEntry>
+    }
+
+    impl UnitAttributes {
+        /// Parses all `#[unit(...)]` attributes of the given input
+        fn parse(input : &DeriveInput) -> syn::Result<Self> {
+            let mut attrs = Self::default();
+
+            for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("unit")) {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("symbol") {
+                        if attrs.symbol.is_some() {
+                            return Err(meta.error("duplicate `symbol`, a unit can only have one symbol"));
+                        }
+
+                        attrs.symbol = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("dimension") {
+                        if attrs.dimension.is_some() {
+                            return Err(meta.error("duplicate `dimension`, a unit can only have one dimension"));
+                        }
+
+                        attrs.dimension = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("scale") {
+                        if attrs.scale.is_some() {
+                            return Err(meta.error("duplicate `scale`, a unit can only have one scale"));
+                        }
+
+                        attrs.scale = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("additive") {
+                        attrs.additive = true;
+                        Ok(())
+                    } else if meta.path.is_ident("position_of") {
+                        if attrs.position_of.is_some() {
+                            return Err(meta.error("duplicate `position_of`, a position can only belong to one distance unit"));
+                        }
+
+                        attrs.position_of = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("derive") {
+                        let mut time = None;
+                        let mut to = None;
+
+                        meta.parse_nested_meta(|inner| {
+                            if inner.path.is_ident("time") {
+                                time = Some(inner.value()?.parse()?);
+                                Ok(())
+                            } else if inner.path.is_ident("to") {
+                                to = Some(inner.value()?.parse()?);
+                                Ok(())
+                            } else {
+                                Err(inner.error("unknown `derive` key, expected `time` or `to`"))
+                            }
+                        })?;
+
+                        attrs.derives.push(DeriveEntry {
+                            time: time.ok_or_else(|| meta.error("missing `time = ...` in `derive(...)`, e.g. `derive(time = Seconds, to = MMPerSecond)`"))?,
+                            to: to.ok_or_else(|| meta.error("missing `to = ...` in `derive(...)`, e.g. `derive(time = Seconds, to = MMPerSecond)`"))?
+                        });
+                        Ok(())
+                    } else {
+                        Err(meta.error("unknown unit attribute, expected `symbol`, `dimension`, `scale`, `additive`, `position_of` or `derive`"))
+                    }
+                })?;
+            }
+
+            Ok(attrs)
+        }
+    }
+//
+
+// ################
+// #    Checks    #
+// ################
+    /// Makes sure the input is a non-generic tuple struct with a single `f32` field
+    fn check_structure(input : &DeriveInput) -> syn::Result<()> {
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(&input.generics, "units cannot have generic parameters"));
+        }
+
+        let data = match &input.data {
+            Data::Struct(data) => data,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "`Unit` can only be derived for tuple structs like `struct Millimeters(pub f32);`"))
+        };
+
+        let field = match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => return Err(syn::Error::new_spanned(&data.fields, "`Unit` can only be derived for tuple structs with a single `f32` field, like `struct Millimeters(pub f32);`"))
+        };
+
+        match &field.ty {
+            Type::Path(path) if path.qself.is_none() && path.path.is_ident("f32") => Ok(()),
+            ty => Err(syn::Error::new_spanned(ty, "the field of a unit must be of type `f32`"))
+        }
+    }
+//
+
+// ###############
+// #    Unit     #
+// ###############
+    /// Derives everything required for a unit, replacing the calls of the `basic_unit!`, `additive_unit!`, `position_unit!`
+    /// and `derive_units!` macros of `syunit`
+    ///
+    /// The struct must be a tuple struct with a single `f32` field. The traits `Clone`, `Copy`, `Default`, `PartialEq` and
+    /// `PartialOrd` still have to be derived manually.
+    ///
+    /// # Attributes
+    ///
+    /// All options are given with the `#[unit(...)]` attribute, which may be used multiple times
+    ///
+    /// - `symbol = "mm"`: The symbol used when displaying the unit, requires a `dimension` and a `scale`
+    /// - `dimension = Dimension::LENGTH`: The dimension of the unit, see `syunit::Unit::DIMENSION`
+    /// - `scale = 0.001`: The scale of the unit to SI units, see `syunit::Unit::SCALE_TO_SI`
+    ///
+    /// The `symbol`, `dimension` and `scale` are either all given or all omitted, a unit without a symbol has no dimension
+    /// - `additive`: The unit can be added and subtracted from itself, see `syunit::AdditiveUnit`
+    /// - `position_of = Millimeters`: The unit is an absolute position of the given distance unit
+    /// - `derive(time = Seconds, to = MMPerSecond)`: Deriving the unit by `time` results in `to`, may be given multiple times
+    ///
+    /// All types given must be in scope and referenced by their name only
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use syunit::prelude::*;
+    /// use syunit_derive::Unit;
+    ///
+    /// /// Micrometers (um)
+    /// #[derive(Clone, Copy, Default, PartialEq, PartialOrd, Unit)]
+    /// #[unit(symbol = "um", dimension = Dimension::LENGTH, scale = 0.000_001)]
+    /// #[unit(additive, derive(time = Seconds, to = UMPerSecond))]
+    /// pub struct Micrometers(pub f32);
+    ///
+    /// /// Position in micrometers (um)
+    /// #[derive(Clone, Copy, Default, PartialEq, PartialOrd, Unit)]
+    /// #[unit(symbol = "um", dimension = Dimension::LENGTH, scale = 0.000_001, position_of = Micrometers)]
+    /// pub struct PositionUM(pub f32);
+    ///
+    /// /// Micrometers per second (um/s)
+    /// #[derive(Clone, Copy, Default, PartialEq, PartialOrd, Unit)]
+    /// #[unit(symbol = "um/s", dimension = Dimension::new(1, 0, -1, 0), scale = 0.000_001, additive)]
+    /// pub struct UMPerSecond(pub f32);
+    ///
+    /// assert_eq!(Micrometers(2.0) + Micrometers(3.0), Micrometers(5.0));
+    /// assert_eq!(PositionUM(5.0) - PositionUM(3.0), Micrometers(2.0));
+    /// assert_eq!(Micrometers(6.0) / Seconds(2.0), UMPerSecond(3.0));
+    /// assert_eq!(format!("{}", UMPerSecond(3.0)), "3um/s");
+    /// assert_eq!(Micrometers(1000.0).to_si(), 0.001);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Invalid structs and attributes result in compile errors pointing at the problem
+    ///
+    /// ```rust, compile_fail
+    /// use syunit_derive::Unit;
+    ///
+    /// #[derive(Clone, Copy, Default, PartialEq, PartialOrd, Unit)]
+    /// pub struct Millimeters(pub f64);       // error: the field of a unit must be of type `f32`
+    /// ```
+    ///
+    /// ```rust, compile_fail
+    /// use syunit_derive::Unit;
+    ///
+    /// #[derive(Clone, Copy, Default, PartialEq, PartialOrd, Unit)]
+    /// #[unit(symbol = "mm", addative)]        // error: unknown unit attribute, expected `symbol`, `dimension`, `scale`, `additive`, `position_of` or `derive`
+    /// pub struct Millimeters(pub f32);
+    /// ```
+    ///
+    /// ```rust, compile_fail
+    /// use syunit::prelude::*;
+    /// use syunit_derive::Unit;
+    ///
+    /// #[derive(Clone, Copy, Default, PartialEq, PartialOrd, Unit)]
+    /// #[unit(symbol = "um", dimension = Dimension::LENGTH, scale = 0.000_001)]
+    /// #[unit(derive(to = MMPerSecond))]       // error: missing `time = ...` in `derive(...)`
+    /// pub struct Micrometers(pub f32);
+    /// ```
+    ///
+    /// ```rust, compile_fail
+    /// use syunit_derive::Unit;
+    ///
+    /// #[derive(Clone, Copy, Default, PartialEq, PartialOrd, Unit)]
+    /// #[unit(symbol = "mm")]                  // error: a `symbol` requires a `dimension` and a `scale`
+    /// pub struct Millimeters(pub f32);
+    /// ```
+    #[proc_macro_derive(Unit, attributes(unit))]
+    pub fn derive_unit(input : TokenStream) -> TokenStream {
+        let input = parse_macro_input!(input as DeriveInput);
+
+        expand_unit(&input)
+            .unwrap_or_else(syn::Error::into_compile_error)
+            .into()
+    }
+
+    /// Generates the macro calls for the given unit
+    fn expand_unit(input : &DeriveInput) -> syn::Result<TokenStream2> {
+        check_structure(input)?;
+
+        let attrs = UnitAttributes::parse(input)?;
+        let name = &input.ident;
+
+        if attrs.additive {
+            if let Some(dist) = &attrs.position_of {
+                return Err(syn::Error::new_spanned(dist, "a position unit cannot be `additive`, only its distance unit can be"));
+            }
+        }
+
+        if let (Some(scale), None) = (&attrs.scale, &attrs.dimension) {
+            return Err(syn::Error::new_spanned(scale, "a `scale` requires a `dimension`"));
+        }
+
+        let basic = match (&attrs.symbol, &attrs.dimension) {
+            (Some(symbol), Some(dimension)) => match &attrs.scale {
+                Some(scale) => quote! { ::syunit::basic_unit!(#name, #symbol, #dimension, #scale); },
+                None => return Err(syn::Error::new_spanned(dimension, "a `dimension` requires a `scale`"))
+            },
+            (Some(symbol), None) => return Err(syn::Error::new_spanned(symbol, "a `symbol` requires a `dimension` and a `scale`")),
+            (None, Some(dimension)) => return Err(syn::Error::new_spanned(dimension, "a `dimension` requires a `symbol`")),
+            (None, None) => quote! { ::syunit::basic_unit!(#name); }
+        };
+
+        let additive = if attrs.additive {
+            quote! { ::syunit::additive_unit!(#name); }
+        } else {
+            TokenStream2::new()
+        };
+
+        let position = match &attrs.position_of {
+            Some(dist) => quote! { ::syunit::position_unit!(#name, #dist); },
+            None => TokenStream2::new()
+        };
+
+        let derives = attrs.derives.iter().map(|DeriveEntry { time, to }| {
+            quote! { ::syunit::derive_units!(#name, #to, #time); }
+        });
+
+        Ok(quote! {
+            #basic
+            #additive
+            #position
+            #( #derives )*
+        })
+    }
+//
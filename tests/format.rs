@@ -0,0 +1,105 @@
+use syunit::prelude::*;
+
+// #################
+// #    Display    #
+// #################
+    #[test]
+    fn display_plain() {
+        assert_eq!(format!("{}", Millimeters(1.5)), "1.5mm");
+        assert_eq!(format!("{}", Seconds(2.0)), "2s");
+        assert_eq!(format!("{}", MMPerSecond(-3.25)), "-3.25mm/s");
+    }
+
+    #[test]
+    fn display_precision() {
+        assert_eq!(format!("{:.2}", Millimeters(1.23456)), "1.23mm");
+        assert_eq!(format!("{:.0}", Millimeters(1.5)), "2mm");
+        assert_eq!(format!("{:.3}", Hertz(50.0)), "50.000Hz");
+    }
+
+    #[test]
+    fn display_width_and_alignment() {
+        // Right aligned by default, just like numbers
+        assert_eq!(format!("{:8}", Millimeters(1.5)), "   1.5mm");
+        assert_eq!(format!("{:>8}", Millimeters(1.5)), "   1.5mm");
+        assert_eq!(format!("{:<8}", Millimeters(1.5)), "1.5mm   ");
+        assert_eq!(format!("{:^9}", Millimeters(1.5)), "  1.5mm  ");
+
+        // Width smaller than the content
+        assert_eq!(format!("{:2}", Millimeters(1.5)), "1.5mm");
+    }
+
+    #[test]
+    fn display_fill() {
+        assert_eq!(format!("{:*>8}", Millimeters(1.5)), "***1.5mm");
+        assert_eq!(format!("{:-<8}", Millimeters(1.5)), "1.5mm---");
+        assert_eq!(format!("{:_^9.2}", Seconds(1.0)), "__1.00s__");
+    }
+
+    #[test]
+    fn display_sign() {
+        assert_eq!(format!("{:+}", Newtons(2.0)), "+2N");
+        assert_eq!(format!("{:+}", Newtons(-2.0)), "-2N");
+        assert_eq!(format!("{:+.1}", Newtons(2.0)), "+2.0N");
+    }
+
+    #[test]
+    fn display_zero_padding() {
+        assert_eq!(format!("{:08.2}", Millimeters(1.5)), "001.50mm");
+        assert_eq!(format!("{:08.2}", Millimeters(-1.5)), "-01.50mm");
+        assert_eq!(format!("{:+08}", Millimeters(1.5)), "+001.5mm");
+    }
+
+    #[test]
+    fn display_alternate() {
+        assert_eq!(format!("{:#}", Millimeters(1.5)), "1.5 mm");
+        assert_eq!(format!("{:#.1}", RadPerSecond(2.0)), "2.0 rad/s");
+        assert_eq!(format!("{:>#8}", Millimeters(1.5)), "  1.5 mm");
+    }
+
+    #[test]
+    fn display_special_values() {
+        assert_eq!(format!("{}", Millimeters::NAN), "NaNmm");
+        assert_eq!(format!("{}", Millimeters::INFINITY), "infmm");
+        assert_eq!(format!("{:>7}", Millimeters::NEG_INFINITY), " -infmm");
+    }
+
+    #[test]
+    fn display_wrappers() {
+        assert_eq!(format!("{:.1}", FiniteUnit::new(Millimeters(1.25)).unwrap()), "1.2mm");
+        assert_eq!(format!("{:>6}", OrdUnit::new(Hertz(5.0))), "   5Hz");
+        assert_eq!(format!("{:.2}", DynQuantity::from(Millimeters(1.5))), "1.50mm");
+    }
+//
+
+// ###############
+// #    Debug    #
+// ###############
+    #[test]
+    fn debug_forwards_flags() {
+        assert_eq!(format!("{:?}", Millimeters(1.5)), "Millimeters(1.5)");
+        assert_eq!(format!("{:.2?}", Millimeters(1.5)), "Millimeters(1.50)");
+        assert_eq!(format!("{:+?}", Newtons(2.0)), "Newtons(+2.0)");
+        assert_eq!(format!("{:06.2?}", Seconds(1.5)), "Seconds(001.50)");
+    }
+//
+
+// ################
+// #    Factor    #
+// ################
+    #[test]
+    fn factor_display() {
+        assert_eq!(format!("{}", Factor::HALF), "0.5");
+        assert_eq!(format!("{:.2}", Factor::HALF), "0.50");
+        assert_eq!(format!("{:>5}", Factor::HALF), "  0.5");
+        assert_eq!(format!("{:<5}|", Factor::HALF), "0.5  |");
+        assert_eq!(format!("{:+}", Factor::HALF), "+0.5");
+        assert_eq!(format!("{:06.3}", Factor::HALF), "00.500");
+    }
+
+    #[test]
+    fn factor_debug() {
+        assert_eq!(format!("{:?}", Factor::HALF), "Factor(0.5)");
+        assert_eq!(format!("{:.2?}", Factor::HALF), "Factor(0.50)");
+    }
+//
@@ -0,0 +1,62 @@
+//! The kinematics equations used with a [UnitSet] defined by `unit_system!`
+
+use syunit::prelude::*;
+use syunit::kinematics::*;
+
+syunit::unit_system! {
+    /// A [UnitSet] centered around micrometers
+    pub struct MetricUM : MetricMM {
+        time : Seconds;
+        /// Position in micrometers (um)
+        position : PositionUM("um") = 0.001 * PositionMM;
+        /// Micrometers (um)
+        distance : Micrometers("um") = 0.001 * Millimeters;
+        /// Micrometers per second (um/s)
+        velocity : UMPerSecond("um/s") = 0.001 * MMPerSecond;
+        /// Micrometers per second squared (um/s^2)
+        acceleration : UMPerSecond2("um/s^2") = 0.001 * MMPerSecond2;
+        /// Micrometers per second cubed (um/s^3)
+        jolt : UMPerSecond3("um/s^3") = 0.001 * MMPerSecond3;
+        /// Millinewtons (mN)
+        force : MilliNewtons("mN") = 0.001 * Newtons;
+        /// Grams (g)
+        inertia : Grams("g") = 0.001 * Kilogramms;
+    }
+}
+
+/// Relative comparison of two values, allowing for a few ULPs of rounding error
+fn approx(a : f32, b : f32) -> bool {
+    (a - b).abs() <= a.abs().max(b.abs()) * 1e-5
+}
+
+#[test]
+fn vel_after_dist_in_custom_set() {
+    let vel = vel_after_dist::<MetricUM>(UMPerSecond(3.0), UMPerSecond2(2.0), Micrometers(4.0));
+    assert!(approx(vel.0, 5.0), "{:?}", vel);
+
+    let vel = vel_after_dist::<MetricUM>(UMPerSecond(3.0), UMPerSecond2(-2.0), Micrometers(-4.0));
+    assert!(approx(vel.0, -5.0), "{:?}", vel);
+
+    assert!(vel_after_dist::<MetricUM>(UMPerSecond(3.0), UMPerSecond2(-2.0), Micrometers(4.0)).is_nan());
+}
+
+#[test]
+fn velocity_jolt_in_custom_set() {
+    // Maximum acceleration is reached
+    let time = time_for_velocity_jolt::<MetricUM>(UMPerSecond(0.0), UMPerSecond(8.0), UMPerSecond2(2.0), UMPerSecond3(1.0));
+    assert!(approx(time.0, 6.0), "{:?}", time);
+
+    // Maximum acceleration is not reached
+    let time = time_for_velocity_jolt::<MetricUM>(UMPerSecond(0.0), UMPerSecond(1.0), UMPerSecond2(2.0), UMPerSecond3(1.0));
+    assert!(approx(time.0, 2.0), "{:?}", time);
+
+    let dist = dist_for_velocity_jolt::<MetricUM>(UMPerSecond(0.0), UMPerSecond(8.0), UMPerSecond2(2.0), UMPerSecond3(1.0));
+    assert!(approx(dist.0, 24.0), "{:?}", dist);
+}
+
+#[test]
+fn matches_reference_set() {
+    let vel = vel_after_dist::<MetricUM>(UMPerSecond(3000.0), UMPerSecond2(2000.0), Micrometers(4000.0));
+    let vel_ref = vel_after_dist::<MetricMM>(MMPerSecond(3.0), MMPerSecond2(2.0), Millimeters(4.0));
+    assert!(approx(MMPerSecond::from(vel).0, vel_ref.0), "{:?} != {:?}", vel, vel_ref);
+}
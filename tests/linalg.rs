@@ -0,0 +1,49 @@
+#![cfg(any(feature = "nalgebra", feature = "glam"))]
+
+use syunit::prelude::*;
+use syunit::linalg::*;
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_points_and_vectors() {
+    let start : nalgebra::Point3<f32> = [ PositionMM(1.0), PositionMM(2.0), PositionMM(3.0) ].to_point();
+    let end : nalgebra::Point3<f32> = [ PositionMM(4.0), PositionMM(6.0), PositionMM(3.0) ].to_point();
+
+    // Point - Point = Vector
+    let travel : [Millimeters; 3] = FromVector::from_vector(end - start);
+    assert_eq!(travel, [ Millimeters(3.0), Millimeters(4.0), Millimeters(0.0) ]);
+
+    // Point + Vector = Point
+    let offset : nalgebra::Vector3<f32> = [ Millimeters(1.0), Millimeters(-1.0), Millimeters(0.5) ].to_vector();
+    let moved : [PositionMM; 3] = FromPoint::from_point(end + offset);
+    assert_eq!(moved, [ PositionMM(5.0), PositionMM(5.0), PositionMM(3.5) ]);
+
+    // Matches the array helpers of the library
+    assert_eq!(moved, add_unit_arrays([ PositionMM(4.0), PositionMM(6.0), PositionMM(3.0) ], [ Millimeters(1.0), Millimeters(-1.0), Millimeters(0.5) ]));
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_other_dimensions() {
+    let point : nalgebra::Point2<f32> = [ PositionRad(0.5), PositionRad(1.5) ].to_point();
+    assert_eq!(point, nalgebra::Point2::new(0.5, 1.5));
+
+    let vector : nalgebra::SVector<f32, 4> = [ Newtons(1.0), Newtons(2.0), Newtons(3.0), Newtons(4.0) ].to_vector();
+    assert_eq!(<[Newtons; 4]>::from_vector(vector), [ Newtons(1.0), Newtons(2.0), Newtons(3.0), Newtons(4.0) ]);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_vectors() {
+    let start : glam::Vec3 = [ PositionMM(1.0), PositionMM(2.0), PositionMM(3.0) ].to_point();
+    let end : glam::Vec3 = [ PositionMM(4.0), PositionMM(6.0), PositionMM(3.0) ].to_point();
+
+    let travel = <[Millimeters; 3]>::from_vector(end - start);
+    assert_eq!(travel, [ Millimeters(3.0), Millimeters(4.0), Millimeters(0.0) ]);
+
+    let pos2 : glam::Vec2 = [ PositionM(1.0), PositionM(2.0) ].to_point();
+    assert_eq!(<[PositionM; 2]>::from_point(pos2), [ PositionM(1.0), PositionM(2.0) ]);
+
+    let vel : glam::Vec4 = [ MMPerSecond(1.0); 4 ].to_vector();
+    assert_eq!(vel, glam::Vec4::ONE);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "num-traits")]
+
+use num_traits::{Bounded, FromPrimitive, One, ToPrimitive, Zero};
+use syunit::prelude::*;
+
+/// Generic function written against `num-traits`, like a filter or solver crate would
+fn sum_positive<T : Zero + PartialOrd + Copy>(values : &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &value| if value > T::zero() { acc + value } else { acc })
+}
+
+#[test]
+fn zero() {
+    assert_eq!(Millimeters::zero(), Millimeters::ZERO);
+    assert!(Millimeters(0.0).is_zero());
+    assert!(Millimeters(-0.0).is_zero());
+    assert!(!Millimeters(1.0).is_zero());
+
+    assert_eq!(sum_positive(&[ Newtons(2.0), Newtons(-1.0), Newtons(3.0) ]), Newtons(5.0));
+}
+
+#[test]
+fn bounded() {
+    assert_eq!(Hertz::min_value(), Hertz::MIN);
+    assert_eq!(PositionMM::max_value(), PositionMM::MAX);
+    assert_eq!(Factor::min_value(), Factor::MIN);
+    assert_eq!(Factor::max_value(), Factor::MAX);
+}
+
+#[test]
+fn one() {
+    assert_eq!(Factor::one(), Factor::MAX);
+    assert!(Factor::one().is_one());
+}
+
+#[test]
+fn from_primitive() {
+    assert_eq!(Millimeters::from_i32(-4), Some(Millimeters(-4.0)));
+    assert_eq!(Seconds::from_f64(0.5), Some(Seconds(0.5)));
+    assert_eq!(Factor::from_f32(0.5), Some(Factor::HALF));
+    assert_eq!(Factor::from_u8(1), Some(Factor::MAX));
+    assert_eq!(Factor::from_i32(2), None);
+    assert_eq!(Factor::from_f64(-0.1), None);
+}
+
+#[test]
+fn to_primitive() {
+    assert_eq!(Millimeters(2.7).to_i32(), Some(2));
+    assert_eq!(Millimeters(-2.7).to_u32(), None);
+    assert_eq!(Millimeters::NAN.to_i64(), None);
+    assert_eq!(Seconds(0.5).to_f64(), Some(0.5));
+    assert_eq!(Factor::HALF.to_f32(), Some(0.5));
+}
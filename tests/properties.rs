@@ -0,0 +1,202 @@
+//! Algebraic laws of the operators and conversions generated by the macros of the library
+
+use proptest::prelude::*;
+use syunit::prelude::*;
+use syunit::imperial::Inches;
+
+/// Relative comparison of two values, allowing for a few ULPs of rounding error
+fn approx(a : f32, b : f32) -> bool {
+    (a - b).abs() <= a.abs().max(b.abs()) * 1e-5
+}
+
+/// Strategy for values of a reasonable magnitude with both signs
+fn value() -> impl Strategy<Value = f32> {
+    prop_oneof![ -1e4f32 .. -1e-2, 1e-2f32 .. 1e4 ]
+}
+
+/// Checks `(a * b) / b = a` and `(a * b) / a = b` for multiplications implemented in both directions
+macro_rules! mul_div_laws {
+    ( $name:ident, $a:ident, $b:ident, $out:ident ) => {
+        proptest! {
+            #[test]
+            fn $name(a in value(), b in value()) {
+                let (a, b) = ($a(a), $b(b));
+                let out : $out = a * b;
+
+                prop_assert_eq!(b * a, out);
+                prop_assert!(approx((out / b).0, a.0), "({:?} * {:?}) / {:?} = {:?}", a, b, b, out / b);
+                prop_assert!(approx((out / a).0, b.0), "({:?} * {:?}) / {:?} = {:?}", a, b, a, out / a);
+            }
+        }
+    };
+}
+
+/// Checks that converting back and forth between two units keeps the value
+macro_rules! conversion_laws {
+    ( $name:ident, $a:ident, $b:ident, $conv:expr ) => {
+        proptest! {
+            #[test]
+            fn $name(value in value()) {
+                let b = $b::from($a(value));
+
+                prop_assert!(approx(b.0, value * $conv), "{:?} -> {:?}", $a(value), b);
+                prop_assert!(approx($a::from(b).0, value));
+                prop_assert!(approx($b::from($a::from($b(value))).0, value));
+            }
+        }
+    };
+}
+
+/// Checks that [InertiaUnit::extend] reverses [InertiaUnit::reduce]
+macro_rules! inertia_laws {
+    ( $name:ident, $inertia:ident, $ratio:expr, $reduced:ident, $conv:expr ) => {
+        proptest! {
+            #[test]
+            fn $name(inertia in value(), ratio in value()) {
+                let inertia = $inertia(inertia);
+                let ratio = $ratio(ratio);
+                let ratio_f32 : f32 = ratio.into();
+
+                let reduced : $reduced = inertia.reduce(ratio);
+                prop_assert!(approx(reduced.0, inertia.0 * ratio_f32 * ratio_f32 * $conv), "{:?} reduced by {:?} = {:?}", inertia, ratio, reduced);
+
+                let extended = $inertia::extend(reduced, ratio);
+                prop_assert!(approx(extended.0, inertia.0), "{:?} extended by {:?} = {:?}", reduced, ratio, extended);
+            }
+        }
+    };
+}
+
+// ###############################
+// #    Derivatives over time    #
+// ###############################
+    mul_div_laws!(rad_per_second_times_seconds, RadPerSecond, Seconds, Radians);
+    mul_div_laws!(rad_per_second2_times_seconds, RadPerSecond2, Seconds, RadPerSecond);
+    mul_div_laws!(rad_per_second3_times_seconds, RadPerSecond3, Seconds, RadPerSecond2);
+    mul_div_laws!(mm_per_second_times_seconds, MMPerSecond, Seconds, Millimeters);
+    mul_div_laws!(mm_per_second2_times_seconds, MMPerSecond2, Seconds, MMPerSecond);
+    mul_div_laws!(mm_per_second3_times_seconds, MMPerSecond3, Seconds, MMPerSecond2);
+//
+
+// ##########################
+// #    Full conversions    #
+// ##########################
+    mul_div_laws!(position_rad_times_millimeters, PositionRad, Millimeters, PositionMM);
+    mul_div_laws!(position_rad_times_meters, PositionRad, Meters, PositionM);
+    mul_div_laws!(rad_per_second_times_millimeters, RadPerSecond, Millimeters, MMPerSecond);
+    mul_div_laws!(rad_per_second2_times_millimeters, RadPerSecond2, Millimeters, MMPerSecond2);
+    mul_div_laws!(rad_per_second3_times_millimeters, RadPerSecond3, Millimeters, MMPerSecond3);
+    mul_div_laws!(rad_per_second2_times_radians, RadPerSecond2, Radians, Rad2PerSecond2);
+    mul_div_laws!(mm_per_second2_times_millimeters, MMPerSecond2, Millimeters, MM2PerSecond2);
+    mul_div_laws!(kg_meter2_times_rad_per_second2, KgMeter2, RadPerSecond2, NewtonMeters);
+    mul_div_laws!(mm_per_second3_times_seconds2, MMPerSecond3, Seconds2, MMPerSecond);
+    mul_div_laws!(rad_per_second3_times_seconds2, RadPerSecond3, Seconds2, RadPerSecond);
+
+    // With conversion literals
+    mul_div_laws!(mm_per_second2_times_kilogramms, MMPerSecond2, Kilogramms, Newtons);
+    mul_div_laws!(newtons_times_millimeters, Newtons, Millimeters, NewtonMeters);
+
+    proptest! {
+        #[test]
+        fn conversion_literals_scale(a in value(), b in value()) {
+            // 1 mm/s^2 * 1 kg = 0.001 N
+            prop_assert!(approx((MMPerSecond2(a) * Kilogramms(b)).0, a * b * 0.001));
+            // 1 N * 1 mm = 0.001 Nm
+            prop_assert!(approx((Newtons(a) * Millimeters(b)).0, a * b * 0.001));
+        }
+
+        #[test]
+        fn radians_scale_distances(rad in value(), dist in value()) {
+            prop_assert_eq!(Radians(rad) * Millimeters(dist), Millimeters(dist) * Radians(rad));
+            prop_assert_eq!(Radians(rad) * Meters(dist), Meters(dist) * Radians(rad));
+            prop_assert!(approx((Radians(rad) * Millimeters(dist)).0, rad * dist));
+        }
+    }
+//
+
+// ###########################
+// #    Squares and cubes    #
+// ###########################
+    mul_div_laws!(millimeters_squared, Millimeters, Millimeters, Millimeters2);
+    mul_div_laws!(meters_squared, Meters, Meters, Meters2);
+    mul_div_laws!(mm_per_second_squared, MMPerSecond, MMPerSecond, MM2PerSecond2);
+    mul_div_laws!(rad_per_second_squared, RadPerSecond, RadPerSecond, Rad2PerSecond2);
+    mul_div_laws!(seconds_squared, Seconds, Seconds, Seconds2);
+
+    proptest! {
+        #[test]
+        fn square_roots(value in value()) {
+            prop_assert!(approx(Millimeters(value).squared().sqrt().0, value.abs()));
+            prop_assert!(approx(MMPerSecond(value).squared().sqrt().0, value.abs()));
+        }
+
+        #[test]
+        fn cubes(value in value()) {
+            prop_assert!(approx(Millimeters(value).cubed().0, value * value * value));
+            prop_assert!(approx(Meters(value).cubed().0, value * value * value));
+        }
+    }
+//
+
+// #####################
+// #    Conversions    #
+// #####################
+    conversion_laws!(meters_to_millimeters, Meters, Millimeters, 1000.0);
+    conversion_laws!(meters2_to_millimeters2, Meters2, Millimeters2, 1_000_000.0);
+    conversion_laws!(meters3_to_millimeters3, Meters3, Millimeters3, 1_000_000_000.0);
+    conversion_laws!(inches_to_millimeters, Inches, Millimeters, 25.4);
+    conversion_laws!(position_mm_to_millimeters, PositionMM, Millimeters, 1.0);
+    conversion_laws!(position_m_to_meters, PositionM, Meters, 1.0);
+    conversion_laws!(position_rad_to_radians, PositionRad, Radians, 1.0);
+    conversion_laws!(timestamp_to_seconds, Timestamp, Seconds, 1.0);
+
+    proptest! {
+        #[test]
+        fn conversions_match_metadata(value in value()) {
+            // The conversion literals agree with the scales of the units
+            prop_assert!(approx(Millimeters::from(Meters(value)).to_si(), Meters(value).to_si()));
+            prop_assert!(approx(Millimeters::from(Inches(value)).to_si(), Inches(value).to_si()));
+            prop_assert!(approx(Millimeters2::from(Meters2(value)).to_si(), Meters2(value).to_si()));
+        }
+    }
+//
+
+// #################
+// #    Inertia    #
+// #################
+    inertia_laws!(kilogramms_by_ratio, Kilogramms, |ratio : f32| ratio, Kilogramms, 1.0);
+    inertia_laws!(kilogramms_by_millimeters, Kilogramms, Millimeters, KgMeter2, 0.000_001);
+    inertia_laws!(kg_meter2_by_ratio, KgMeter2, |ratio : f32| ratio, KgMeter2, 1.0);
+    inertia_laws!(kg_meter2_by_millimeters, KgMeter2, Millimeters, Kilogramms, 1_000_000.0);
+//
+
+// ####################
+// #    Generators    #
+// ####################
+    #[cfg(feature = "proptest")]
+    proptest! {
+        #[test]
+        fn generated_factors_are_valid(factor in any::<Factor>()) {
+            prop_assert!((0.0 ..= 1.0).contains(&factor.as_f32()));
+        }
+
+        #[test]
+        fn generated_units_round_trip(dist in any::<Millimeters>(), dir in any::<Direction>()) {
+            prop_assume!(dist.is_finite());
+            prop_assert_eq!((dist * dir) * dir, dist);
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_generators() {
+        use arbitrary::Unstructured;
+
+        let data = [ 0xFFu8, 0xFF, 0x00, 0x00, 0x80, 0x3F, 0x01, 0x12, 0x34, 0x56 ];
+        let mut u = Unstructured::new(&data);
+
+        assert_eq!(u.arbitrary::<Factor>().unwrap(), Factor::MAX);
+        assert_eq!(u.arbitrary::<Millimeters>().unwrap(), Millimeters(1.0));
+        assert!(u.arbitrary::<Direction>().is_ok());
+    }
+//
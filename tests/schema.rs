@@ -0,0 +1,124 @@
+#![cfg(feature = "schemars")]
+
+use regex::Regex;
+use schemars::{schema_for, JsonSchema, SchemaGenerator};
+use serde_json::json;
+use syunit::prelude::*;
+use syunit::imperial::Inches;
+
+/// The `pattern` of the string form of the schema created by [syunit::schema::with_symbol]
+fn symbol_pattern<U : Unit>() -> Regex {
+    let schema = syunit::schema::with_symbol::<U>(&mut SchemaGenerator::default());
+    Regex::new(schema.get("anyOf").unwrap()[1]["pattern"].as_str().unwrap()).unwrap()
+}
+
+#[test]
+fn number_form() {
+    let schema = schema_for!(MMPerSecond);
+
+    assert_eq!(schema.get("type"), Some(&json!("number")));
+    assert_eq!(schema.get("title"), Some(&json!("MMPerSecond")));
+    assert_eq!(schema.get("description"), Some(&json!("Value in mm/s")));
+    assert_eq!(schema.get("x-unit"), Some(&json!("mm/s")));
+
+    assert_eq!(schema_for!(Inches).get("x-unit"), Some(&json!("in")));
+    assert_eq!(schema_for!(Seconds).get("x-unit"), Some(&json!("s")));
+}
+
+#[test]
+fn units_without_symbol() {
+    #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+    struct Steps(f32);
+
+    syunit::basic_unit!(Steps);
+
+    let schema = schema_for!(Steps);
+
+    assert_eq!(schema.get("type"), Some(&json!("number")));
+    assert_eq!(schema.get("title"), Some(&json!("Steps")));
+    assert_eq!(schema.get("x-unit"), None);
+    assert!(symbol_pattern::<Steps>().is_match("12"));
+}
+
+#[test]
+fn units_are_inlined() {
+    assert!(Millimeters::inline_schema());
+    assert!(Factor::inline_schema());
+    assert!(Direction::inline_schema());
+
+    let mut generator = SchemaGenerator::default();
+    let schema = generator.subschema_for::<Millimeters>();
+
+    assert_eq!(schema.get("$ref"), None);
+    assert!(generator.definitions().is_empty());
+}
+
+#[test]
+fn factor_bounds() {
+    let schema = schema_for!(Factor);
+
+    assert_eq!(schema.get("type"), Some(&json!("number")));
+    assert_eq!(schema.get("minimum"), Some(&json!(0.0)));
+    assert_eq!(schema.get("maximum"), Some(&json!(1.0)));
+}
+
+#[test]
+fn direction_variants() {
+    let schema = schema_for!(Direction);
+    assert_eq!(schema.get("enum"), Some(&json!([ "CCW", "CW" ])));
+
+    // The schema matches the serialization
+    assert_eq!(serde_json::to_value(Direction::CCW).unwrap(), json!("CCW"));
+    assert_eq!(serde_json::to_value(Polarity::Inverted).unwrap(), json!("Inverted"));
+    assert_eq!(schema_for!(Polarity).get("enum"), Some(&json!([ "Normal", "Inverted" ])));
+}
+
+#[test]
+fn symbol_form() {
+    let pattern = symbol_pattern::<Millimeters>();
+
+    // Everything produced by the serialization matches
+    for value in [ 0.0, 12.5, -3.0, 1e-7, 1e20 ] {
+        let serialized = serde_json::to_value(Wrapper(Millimeters(value))).unwrap();
+        assert!(pattern.is_match(serialized.as_str().unwrap()), "{}", serialized);
+    }
+
+    // Other symbols of the same dimension and plain numbers
+    assert!(pattern.is_match("0.5 m"));
+    assert!(pattern.is_match("2in"));
+    assert!(pattern.is_match("12"));
+
+    // Other dimensions and invalid numbers
+    assert!(!pattern.is_match("2s"));
+    assert!(!pattern.is_match("2mm/s"));
+    assert!(!pattern.is_match("mm"));
+
+    // Symbols with special characters are escaped
+    let pattern = symbol_pattern::<MMPerSecond2>();
+    assert!(pattern.is_match("2mm/s^2"));
+    assert!(!pattern.is_match("2mm/ss"));
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Wrapper(#[serde(with = "syunit::serde_symbol")] Millimeters);
+
+#[test]
+fn symbol_form_round_trip() {
+    for value in [ 0.0, 12.5, -3.0, 1e-7, 1e20 ] {
+        let json = serde_json::to_string(&Wrapper(Millimeters(value))).unwrap();
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap().0, Millimeters(value));
+    }
+
+    assert_eq!(serde_json::from_str::<Wrapper>(r#""1in""#).unwrap().0, Millimeters(25.4));
+    assert_eq!(serde_json::from_str::<Wrapper>(r#""7""#).unwrap().0, Millimeters(7.0));
+    assert!(serde_json::from_str::<Wrapper>(r#""7 parsec""#).is_err());
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct AngularWrapper(#[serde(with = "syunit::serde_symbol")] RadPerSecond);
+
+#[test]
+fn symbol_form_checks_angle() {
+    assert_eq!(serde_json::from_str::<AngularWrapper>(r#""2 rad/s""#).unwrap().0, RadPerSecond(2.0));
+    assert!(serde_json::from_str::<AngularWrapper>(r#""2 Hz""#).is_err());
+}
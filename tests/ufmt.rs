@@ -0,0 +1,44 @@
+#![cfg(feature = "ufmt")]
+
+use syunit::prelude::*;
+use ufmt::{uDisplay, uWrite};
+
+/// Minimal writer collecting the output of `ufmt`
+#[derive(Default)]
+struct Output(String);
+
+impl uWrite for Output {
+    type Error = core::convert::Infallible;
+
+    fn write_str(&mut self, s : &str) -> Result<(), Self::Error> {
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+fn ufmt_string<T : uDisplay>(value : &T) -> String {
+    let mut out = Output::default();
+    ufmt::uwrite!(out, "{}", value).unwrap();
+    out.0
+}
+
+#[test]
+fn units() {
+    assert_eq!(ufmt_string(&Millimeters(1.5)), "1.5mm");
+    assert_eq!(ufmt_string(&Millimeters(-0.25)), "-0.25mm");
+    assert_eq!(ufmt_string(&Hertz(12_000_000.0)), "12000000Hz");
+    assert_eq!(ufmt_string(&Seconds(0.000_001)), "0.000001s");
+    assert_eq!(ufmt_string(&Seconds(0.999_999_9)), "1s");
+    assert_eq!(ufmt_string(&Millimeters(-1e-9)), "0mm");
+    assert_eq!(ufmt_string(&Millimeters(-0.000_000_4)), "0mm");
+    assert_eq!(ufmt_string(&Millimeters(-0.000_000_6)), "-0.000001mm");
+    assert_eq!(ufmt_string(&MMPerSecond::NAN), "NaNmm/s");
+    assert_eq!(ufmt_string(&MMPerSecond::NEG_INFINITY), "-infmm/s");
+}
+
+#[test]
+fn specials() {
+    assert_eq!(ufmt_string(&Factor::HALF), "0.5");
+    assert_eq!(ufmt_string(&Direction::CW), "CW");
+    assert_eq!(ufmt_string(&Direction::CCW), "CCW");
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "uom")]
+
+use syunit::prelude::*;
+use syunit::imperial::Inches;
+use uom::si::f32::*;
+use uom::si::{angular_velocity, force, frequency, length, time, torque, velocity};
+
+/// Relative comparison, as `uom` stores all values in base units
+fn approx(a : f32, b : f32) -> bool {
+    (a - b).abs() <= b.abs() * 1e-6
+}
+
+#[test]
+fn lengths() {
+    let length = Length::from(Millimeters(1500.0));
+    assert!(approx(length.get::<length::meter>(), 1.5));
+
+    // Scaling between syunit types through uom
+    assert!(approx(Meters::from(length).0, 1.5));
+    assert!(approx(Millimeters::from(Length::from(Inches(1.0))).0, 25.4));
+
+    let supplier = Length::new::<length::centimeter>(12.0);
+    assert!(approx(Millimeters::from(supplier).0, 120.0));
+}
+
+#[test]
+fn kinematics() {
+    let velocity = Velocity::new::<velocity::meter_per_second>(0.25);
+    assert!(approx(MMPerSecond::from(velocity).0, 250.0));
+
+    let acc : Acceleration = MMPerSecond2(2000.0).into();
+    assert!(approx(acc.value, 2.0));
+
+    let jerk : Jerk = MMPerSecond3(3000.0).into();
+    assert!(approx(jerk.value, 3.0));
+
+    assert!(approx(Millimeters2::from(Area::from(Meters2(1.0))).0, 1e6));
+    assert!(approx(Millimeters3::from(Volume::from(Meters3(1.0))).0, 1e9));
+}
+
+#[test]
+fn time_and_angles() {
+    assert!(approx(Time::from(Seconds(2.0)).get::<time::millisecond>(), 2000.0));
+    assert!(approx(Hertz::from(Frequency::new::<frequency::kilohertz>(1.5)).0, 1500.0));
+    assert!(approx(RadPerSecond::from(AngularVelocity::new::<angular_velocity::revolution_per_minute>(60.0)).0, core::f32::consts::TAU));
+    assert_eq!(Radians::from(Angle::from(Radians(1.0))), Radians(1.0));
+
+    let _ : AngularAcceleration = RadPerSecond2(1.0).into();
+    let _ : AngularJerk = RadPerSecond3(1.0).into();
+}
+
+#[test]
+fn dynamics() {
+    assert!(approx(Force::from(Newtons(1500.0)).get::<force::kilonewton>(), 1.5));
+    assert_eq!(Kilogramms::from(Mass::from(Kilogramms(3.0))), Kilogramms(3.0));
+    assert!(approx(NewtonMeters::from(Torque::new::<torque::newton_centimeter>(50.0)).0, 0.5));
+    assert_eq!(KgMeter2::from(MomentOfInertia::from(KgMeter2(0.5))), KgMeter2(0.5));
+}
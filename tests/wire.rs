@@ -0,0 +1,135 @@
+use syunit::*;
+use syunit::imperial::*;
+use syunit::metric::*;
+
+/// Calls the given macro with every unit defined in the root, `metric` and `imperial` modules, as well as the [Factor]
+macro_rules! for_all_units {
+    ( $mac:ident ) => {
+        // Root
+        $mac!(Seconds);
+        $mac!(Timestamp);
+        $mac!(Seconds2);
+        $mac!(Hertz);
+        $mac!(PositionRad);
+        $mac!(Radians);
+        $mac!(RadPerSecond);
+        $mac!(Rad2PerSecond2);
+        $mac!(RadPerSecond2);
+        $mac!(RadPerSecond3);
+        // Metric
+        $mac!(PositionMM);
+        $mac!(Millimeters);
+        $mac!(Millimeters2);
+        $mac!(Millimeters3);
+        $mac!(MMPerSecond);
+        $mac!(MM2PerSecond2);
+        $mac!(MMPerSecond2);
+        $mac!(MMPerSecond3);
+        $mac!(Newtons);
+        $mac!(Kilogramms);
+        $mac!(NewtonMeters);
+        $mac!(KgMeter2);
+        $mac!(PositionM);
+        $mac!(Meters);
+        $mac!(Meters2);
+        $mac!(Meters3);
+        // Imperial
+        $mac!(Inches);
+        // Specials
+        $mac!(Factor);
+    };
+}
+
+/// Values covering signs, fractions, extremes and special floats
+const VALUES : [f32; 8] = [ 0.0, -0.0, 1.0, -2.5, 1e-20, f32::MAX, f32::MIN, f32::INFINITY ];
+
+#[test]
+fn size_and_alignment() {
+    macro_rules! check {
+        ( $unit:ident ) => {
+            assert_eq!(core::mem::size_of::<$unit>(), 4, stringify!($unit));
+            assert_eq!(core::mem::align_of::<$unit>(), core::mem::align_of::<f32>(), stringify!($unit));
+        };
+    }
+
+    for_all_units!(check);
+}
+
+#[test]
+fn le_bytes_round_trip() {
+    macro_rules! check {
+        // The helpers are part of the `Unit` trait
+        ( Factor ) => { };
+        ( $unit:ident ) => {
+            for value in VALUES {
+                let bytes = $unit(value).to_le_bytes();
+
+                assert_eq!(bytes, value.to_le_bytes(), stringify!($unit));
+                assert_eq!($unit::from_le_bytes(bytes).0.to_bits(), value.to_bits(), stringify!($unit));
+            }
+
+            assert!($unit::from_le_bytes($unit::NAN.to_le_bytes()).is_nan(), stringify!($unit));
+        };
+    }
+
+    for_all_units!(check);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn postcard_round_trip() {
+    macro_rules! check {
+        ( $unit:ident ) => {
+            for value in VALUES {
+                // Postcard encodes the unit exactly like the documented wire format
+                let decoded : $unit = postcard::from_bytes(&value.to_le_bytes()).unwrap();
+                assert_eq!(f32::from(decoded).to_bits(), value.to_bits(), stringify!($unit));
+                assert_eq!(postcard::to_allocvec(&decoded).unwrap(), value.to_le_bytes(), stringify!($unit));
+            }
+
+            let decoded : $unit = postcard::from_bytes(&f32::NAN.to_le_bytes()).unwrap();
+            assert!(f32::from(decoded).is_nan(), stringify!($unit));
+        };
+    }
+
+    for_all_units!(check);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_casts() {
+    macro_rules! check {
+        ( $unit:ident ) => {
+            let units : [$unit; 8] = bytemuck::cast(VALUES);
+            let floats : &[f32] = bytemuck::cast_slice(&units);
+
+            assert_eq!(floats, &VALUES[..], stringify!($unit));
+            assert_eq!(f32::from(units[3]), -2.5, stringify!($unit));
+
+            let unit = bytemuck::cast::<[u8; 4], $unit>(1.5f32.to_ne_bytes());
+            assert_eq!(f32::from(unit), 1.5, stringify!($unit));
+            assert_eq!(bytemuck::cast::<$unit, [u8; 4]>(unit), 1.5f32.to_ne_bytes(), stringify!($unit));
+            assert_eq!(f32::from(<$unit as bytemuck::Zeroable>::zeroed()).to_bits(), 0, stringify!($unit));
+        };
+    }
+
+    for_all_units!(check);
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+fn zerocopy_casts() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    macro_rules! check {
+        ( $unit:ident ) => {
+            let unit = $unit::read_from_bytes(&(-2.5f32).to_ne_bytes()).unwrap();
+
+            assert_eq!(f32::from(unit), -2.5, stringify!($unit));
+            assert_eq!(unit.as_bytes(), &(-2.5f32).to_ne_bytes(), stringify!($unit));
+            assert!($unit::read_from_bytes(&[ 0u8; 3 ]).is_err(), stringify!($unit));
+        };
+    }
+
+    for_all_units!(check);
+}